@@ -155,6 +155,45 @@ fn lazy(c: &mut Criterion) {
     }))(c);
 }
 
+/// Generate `count` floats with a linear congruential generator, and format each with `format`.
+fn floats(count: usize, format: impl Fn(f64) -> String) -> String {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut s = String::new();
+    for _ in 0..count {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let x = (state >> 11) as f64 / (1u64 << 53) as f64 * 2e6 - 1e6;
+        s += &format(x);
+        s.push(' ');
+    }
+    s
+}
+
+fn float(c: &mut Criterion) {
+    for (case, s) in [
+        ("float-full", floats(COUNT, |x| format!("{x}"))),
+        ("float-short", floats(COUNT, |x| format!("{x:.3}"))),
+    ] {
+        c.bench_function(&format!("{case}-read_all"), |b| {
+            b.iter(|| {
+                let mut reader = InputStream::new(s.as_bytes());
+                let results: Vec<f64> = reader.read_all();
+                assert_eq!(results.len(), COUNT);
+            })
+        })
+        .bench_function(&format!("{case}-str::parse"), |b| {
+            b.iter(|| {
+                let results: Vec<f64> = s
+                    .split_ascii_whitespace()
+                    .map(|s| unwrap!(s.parse::<f64>()))
+                    .collect();
+                assert_eq!(results.len(), COUNT);
+            })
+        });
+    }
+}
+
 fn config() -> Criterion {
     let config = Criterion::default();
 
@@ -169,6 +208,6 @@ fn config() -> Criterion {
 criterion_group!(
     name = benches;
     config = config();
-    targets = cursor, file
+    targets = cursor, file, float
 );
 criterion_main!(benches);
@@ -55,7 +55,7 @@ fn main() {
     // v
     // 1 2 3
     // 4 5 6
-    let m: Mat<u32> = read!(2, 3);
+    let m: Mat<u32> = read!(mat 2, 3);
     assert_eq!(m, [[1, 2, 3], [4, 5, 6]]);
 
     // Read a matrix of characters from input.
@@ -65,7 +65,7 @@ fn main() {
     // .@/#$
     // !@#!@
     // *&@:,
-    let m: Mat<char> = read!(3, 5);
+    let m: Mat<char> = read!(mat 3, 5);
     assert_eq!(
         m,
         [
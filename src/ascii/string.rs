@@ -134,13 +134,25 @@ impl FromStr for String {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = s.as_bytes();
-        let mut string = Vec::with_capacity(bytes.len());
-        for (i, byte) in bytes.iter().enumerate() {
+        let mut string = Self {
+            bytes: Vec::with_capacity(s.len()),
+        };
+        string.assign(s)?;
+        Ok(string)
+    }
+}
+
+impl String {
+    /// Replace the content with `s`, reusing the allocated buffer.
+    ///
+    /// On error, the content is left unspecified.
+    pub(crate) fn assign(&mut self, s: &str) -> Result<(), Error> {
+        self.bytes.clear();
+        for (i, byte) in s.as_bytes().iter().enumerate() {
             let byte = *byte;
             let byte = byte.try_into().map_err(|byte| Error::Byte(i, byte))?;
-            string.push(byte);
+            self.bytes.push(byte);
         }
-        Ok(Self { bytes: string })
+        Ok(())
     }
 }
@@ -0,0 +1,740 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Base of a limb.
+const BASE: u32 = 1_000_000_000;
+/// Number of decimal digits in a limb.
+const BASE_DIGITS: usize = 9;
+
+/// An arbitrary-precision unsigned integer.
+///
+/// It is stored as little-endian limbs in base `10^9`, so that reading and writing a number with
+/// `n` digits takes `O(n)` time.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::BigUint;
+///
+/// let a: BigUint = "123456789012345678901234567890".parse().unwrap();
+/// let b = &a * 10 + &BigUint::from(5u32);
+/// assert_eq!(b.to_string(), "1234567890123456789012345678905");
+/// assert_eq!(b.divmod(7), ("176366841446208112716049382700".parse().unwrap(), 5));
+/// assert!(a < b);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian limbs, without leading zero limbs.
+    limbs: Vec<u32>,
+}
+
+/// An arbitrary-precision signed integer.
+///
+/// It is stored as a sign and a [BigUint] magnitude.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::BigInt;
+///
+/// let a: BigInt = "-100000000000000000000".parse().unwrap();
+/// let b: BigInt = "99999999999999999999".parse().unwrap();
+/// assert_eq!((&a + &b).to_string(), "-1");
+/// assert_eq!(a.divmod(3), ("-33333333333333333334".parse().unwrap(), 2));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Never set for zero.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+/// Decimal digits of a token, such as `[1, 2, 3]` for `123`.
+///
+/// It is useful for digit DP problems, where numbers are too long for any integer type and only
+/// their digits are needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::Digits;
+///
+/// let digits: Digits = "00123".parse().unwrap();
+/// assert_eq!(*digits, [0, 0, 1, 2, 3]);
+/// assert_eq!(digits.to_string(), "00123");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Digits(pub Vec<u8>);
+
+/// Error returned when parsing a [BigUint], a [BigInt] or [Digits].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    /// The string has no digits.
+    Empty,
+    /// The string contains a character that is not allowed.
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseBigIntError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+        })
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+/// Strip a leading sign from `s`.
+#[inline]
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+impl BigUint {
+    /// Zero.
+    pub const ZERO: Self = Self { limbs: Vec::new() };
+
+    /// Whether it is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Remove leading zero limbs.
+    #[inline]
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            let _: Option<u32> = self.limbs.pop();
+        }
+    }
+
+    /// Subtract `rhs` from `self`, or [None] if `rhs` is larger.
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut result = self.clone();
+        result.sub_assign_unchecked(rhs);
+        Some(result)
+    }
+
+    /// Subtract `rhs` from `self`, where `self >= rhs`.
+    fn sub_assign_unchecked(&mut self, rhs: &Self) {
+        let mut borrow = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let sub = rhs.limbs.get(i).copied().unwrap_or(0) + borrow;
+            if i >= rhs.limbs.len() && sub == 0 {
+                break;
+            }
+            (*limb, borrow) = if *limb >= sub {
+                (*limb - sub, 0)
+            } else {
+                (*limb + BASE - sub, 1)
+            };
+        }
+        debug_assert_eq!(borrow, 0);
+        self.trim();
+    }
+
+    /// Divide by `divisor`, and return the quotient and the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[track_caller]
+    pub fn divmod(&self, divisor: u32) -> (Self, u32) {
+        if divisor == 0 {
+            panic!("attempt to divide by zero");
+        }
+        let mut limbs = self.limbs.clone();
+        let mut remainder = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let cur = remainder * BASE as u64 + *limb as u64;
+            *limb = (cur / divisor as u64) as u32;
+            remainder = cur % divisor as u64;
+        }
+        let mut quotient = Self { limbs };
+        quotient.trim();
+        (quotient, remainder as u32)
+    }
+}
+
+impl From<u64> for BigUint {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u32> for BigUint {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> Self {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push((value % BASE as u128) as u32);
+            value /= BASE as u128;
+        }
+        Self { limbs }
+    }
+}
+
+impl PartialOrd for BigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        let mut carry = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let add = rhs.limbs.get(i).copied().unwrap_or(0) + carry;
+            if i >= rhs.limbs.len() && add == 0 {
+                break;
+            }
+            let sum = *limb + add;
+            (*limb, carry) = if sum >= BASE {
+                (sum - BASE, 1)
+            } else {
+                (sum, 0)
+            };
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let mut result = self.clone();
+        result += rhs;
+        result
+    }
+}
+
+impl Add<&BigUint> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn add(mut self, rhs: &BigUint) -> BigUint {
+        self += rhs;
+        self
+    }
+}
+
+impl SubAssign<&BigUint> for BigUint {
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: &BigUint) {
+        if *self < *rhs {
+            panic!("attempt to subtract with overflow");
+        }
+        self.sub_assign_unchecked(rhs);
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        let mut result = self.clone();
+        result -= rhs;
+        result
+    }
+}
+
+impl Sub<&BigUint> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    #[track_caller]
+    fn sub(mut self, rhs: &BigUint) -> BigUint {
+        self -= rhs;
+        self
+    }
+}
+
+impl MulAssign<u32> for BigUint {
+    fn mul_assign(&mut self, rhs: u32) {
+        if rhs == 0 {
+            self.limbs.clear();
+            return;
+        }
+        let mut carry = 0u64;
+        for limb in &mut self.limbs {
+            let cur = *limb as u64 * rhs as u64 + carry;
+            *limb = (cur % BASE as u64) as u32;
+            carry = cur / BASE as u64;
+        }
+        while carry != 0 {
+            self.limbs.push((carry % BASE as u64) as u32);
+            carry /= BASE as u64;
+        }
+    }
+}
+
+impl Mul<u32> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> BigUint {
+        let mut result = self.clone();
+        result *= rhs;
+        result
+    }
+}
+
+impl Mul<u32> for BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn mul(mut self, rhs: u32) -> BigUint {
+        self *= rhs;
+        self
+    }
+}
+
+impl Div<u32> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: u32) -> BigUint {
+        self.divmod(rhs).0
+    }
+}
+
+impl Rem<u32> for &BigUint {
+    type Output = u32;
+
+    #[inline]
+    #[track_caller]
+    fn rem(self, rhs: u32) -> u32 {
+        self.divmod(rhs).1
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s).as_bytes();
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        let mut limbs = Vec::with_capacity(digits.len().div_ceil(BASE_DIGITS));
+        for chunk in digits.rchunks(BASE_DIGITS) {
+            let mut limb = 0;
+            for &byte in chunk {
+                let digit = byte.wrapping_sub(b'0');
+                if digit >= 10 {
+                    return Err(ParseBigIntError::InvalidDigit);
+                }
+                limb = limb * 10 + digit as u32;
+            }
+            limbs.push(limb);
+        }
+        let mut result = Self { limbs };
+        result.trim();
+        Ok(result)
+    }
+}
+
+impl BigUint {
+    /// Write the digits without any sign or padding.
+    fn write_digits(&self, s: &mut String) {
+        use fmt::Write;
+
+        let mut limbs = self.limbs.iter().rev();
+        match limbs.next() {
+            Some(limb) => {
+                let _: fmt::Result = write!(s, "{limb}");
+            }
+            None => s.push('0'),
+        }
+        for limb in limbs {
+            let _: fmt::Result = write!(s, "{limb:0BASE_DIGITS$}");
+        }
+    }
+}
+
+impl fmt::Display for BigUint {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::with_capacity(self.limbs.len() * BASE_DIGITS);
+        self.write_digits(&mut s);
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl BigInt {
+    /// Zero.
+    pub const ZERO: Self = Self {
+        negative: false,
+        magnitude: BigUint::ZERO,
+    };
+
+    /// Create from a sign and a magnitude.
+    #[inline]
+    pub fn from_sign_magnitude(negative: bool, magnitude: BigUint) -> Self {
+        let negative = negative && !magnitude.is_zero();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    /// Whether it is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Whether it is less than zero.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Get the absolute value.
+    #[inline]
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Divide by `divisor` with floor division, and return the quotient and the remainder.
+    ///
+    /// The remainder is always in `0..divisor`, like `divmod` in Python.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[track_caller]
+    pub fn divmod(&self, divisor: u32) -> (Self, u32) {
+        let (quotient, remainder) = self.magnitude.divmod(divisor);
+        if self.negative && remainder != 0 {
+            let quotient = quotient + &BigUint::from(1u32);
+            (
+                Self::from_sign_magnitude(true, quotient),
+                divisor - remainder,
+            )
+        } else {
+            (
+                Self::from_sign_magnitude(self.negative, quotient),
+                remainder,
+            )
+        }
+    }
+
+    /// Add `rhs` with its sign flipped if `negate` is set.
+    fn add_signed(&self, rhs: &Self, negate: bool) -> Self {
+        let rhs_negative = rhs.negative != negate && !rhs.is_zero();
+        if self.negative == rhs_negative {
+            return Self::from_sign_magnitude(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+        match self.magnitude.cmp(&rhs.magnitude) {
+            Ordering::Less => {
+                Self::from_sign_magnitude(rhs_negative, &rhs.magnitude - &self.magnitude)
+            }
+            _ => Self::from_sign_magnitude(self.negative, &self.magnitude - &rhs.magnitude),
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    #[inline]
+    fn from(magnitude: BigUint) -> Self {
+        Self::from_sign_magnitude(false, magnitude)
+    }
+}
+
+impl From<i64> for BigInt {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl From<i128> for BigInt {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self::from_sign_magnitude(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn add(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, false)
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self.add_signed(rhs, true)
+    }
+}
+
+impl AddAssign<&BigInt> for BigInt {
+    #[inline]
+    fn add_assign(&mut self, rhs: &BigInt) {
+        *self = &*self + rhs;
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        *self = &*self - rhs;
+    }
+}
+
+impl MulAssign<u32> for BigInt {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        self.magnitude *= rhs;
+        self.negative &= !self.magnitude.is_zero();
+    }
+}
+
+impl Mul<u32> for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> BigInt {
+        let mut result = self.clone();
+        result *= rhs;
+        result
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        Self::from_sign_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = split_sign(s);
+        if digits.starts_with('+') {
+            return Err(ParseBigIntError::InvalidDigit);
+        }
+        Ok(Self::from_sign_magnitude(negative, digits.parse()?))
+    }
+}
+
+impl fmt::Display for BigInt {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::with_capacity(self.magnitude.limbs.len() * BASE_DIGITS);
+        self.magnitude.write_digits(&mut s);
+        f.pad_integral(!self.negative, "", &s)
+    }
+}
+
+impl Deref for Digits {
+    type Target = Vec<u8>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Digits {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl FromStr for Digits {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+        s.bytes()
+            .map(|byte| match byte.wrapping_sub(b'0') {
+                digit @ 0..=9 => Ok(digit),
+                _ => Err(ParseBigIntError::InvalidDigit),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl fmt::Display for Digits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s: String = self.0.iter().map(|&digit| (b'0' + digit) as char).collect();
+        f.pad_integral(true, "", &s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigInt, BigUint, Digits, ParseBigIntError};
+
+    fn u(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    fn i(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(u("0"), BigUint::ZERO);
+        assert_eq!(u("000"), BigUint::ZERO);
+        assert_eq!(u("+1000000000"), BigUint::from(1_000_000_000u32));
+        assert_eq!(
+            u("340282366920938463463374607431768211455"),
+            BigUint::from(u128::MAX)
+        );
+        assert_eq!(u("0001000000000").to_string(), "1000000000");
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigIntError::Empty));
+        assert_eq!("-1".parse::<BigUint>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!("1a".parse::<BigUint>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!(i("-0"), BigInt::ZERO);
+        assert!(!i("-0").is_negative());
+        assert_eq!(i("-12"), BigInt::from(-12i64));
+        assert_eq!("-+1".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+        assert_eq!("12a".parse::<Digits>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!("".parse::<Digits>(), Err(ParseBigIntError::Empty));
+    }
+
+    #[test]
+    fn display() {
+        for s in ["0", "1", "999999999", "1000000000", "1000000000000000001"] {
+            assert_eq!(u(s).to_string(), s);
+            assert_eq!(i(s).to_string(), s);
+        }
+        assert_eq!(i("-1000000000").to_string(), "-1000000000");
+        assert_eq!(format!("{:>5}", u("12")), "   12");
+        assert_eq!(format!("{:+}", i("12")), "+12");
+        assert_eq!(format!("{:05}", i("-12")), "-0012");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = u("999999999999999999");
+        assert_eq!(
+            (&a + &BigUint::from(1u32)).to_string(),
+            "1000000000000000000"
+        );
+        assert_eq!((&u("1000000000000000000") - &BigUint::from(1u32)), a);
+        assert_eq!(BigUint::from(1u32).checked_sub(&u("2")), None);
+        assert_eq!((&a * 1000).to_string(), "999999999999999999000");
+        assert_eq!((&a * std::hint::black_box(0)), BigUint::ZERO);
+        assert_eq!(
+            (&u("18446744073709551615") * u32::MAX).to_string(),
+            "79228162495817593515539431425",
+        );
+        assert_eq!(a.divmod(1000), (u("999999999999999"), 999));
+        assert_eq!(&a / 3, u("333333333333333333"));
+        assert_eq!(&a % 10, 9);
+
+        assert_eq!(&i("5") - &i("7"), i("-2"));
+        assert_eq!(&i("-5") + &i("7"), i("2"));
+        assert_eq!(&i("-5") - &i("-5"), BigInt::ZERO);
+        assert_eq!(&i("-5") * 3, i("-15"));
+        assert_eq!(&i("-5") * std::hint::black_box(0), BigInt::ZERO);
+        assert_eq!(-i("5"), i("-5"));
+        assert_eq!(i("-7").divmod(2), (i("-4"), 1));
+        assert_eq!(i("-6").divmod(2), (i("-3"), 0));
+        assert_eq!(i("7").divmod(2), (i("3"), 1));
+    }
+
+    #[test]
+    #[should_panic = "attempt to subtract with overflow"]
+    fn sub_overflow() {
+        let _ = &u("1") - &u("2");
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide by zero"]
+    fn div_zero() {
+        let _ = u("1").divmod(0);
+    }
+
+    #[test]
+    fn compare() {
+        assert!(u("999999999") < u("1000000000"));
+        assert!(u("1000000001") > u("1000000000"));
+        assert!(i("-2") < i("-1"));
+        assert!(i("-1") < i("0"));
+        assert!(i("0") < i("1"));
+    }
+}
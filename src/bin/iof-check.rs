@@ -0,0 +1,66 @@
+//! Compare the output of a solution with the expected output.
+//!
+//! ```txt
+//! iof-check [tokens | lines | floats <eps>] <expected> <output>
+//! ```
+//!
+//! The mode defaults to `tokens`. The verdict is printed to standard output,
+//! and the exit code is 0 if accepted, 1 if wrong answer, or 2 on other errors.
+use iof::{
+    check::{compare_floats, compare_lines, compare_tokens, Verdict},
+    InputStream,
+};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+    process::ExitCode,
+};
+
+const USAGE: &str = "usage: iof-check [tokens | lines | floats <eps>] <expected> <output>";
+
+fn open(path: &str) -> io::Result<InputStream<BufReader<File>>> {
+    Ok(InputStream::new(BufReader::new(File::open(path)?)))
+}
+
+fn run(args: &[String]) -> Result<Verdict, String> {
+    let (mode, eps, paths) = match args {
+        [mode, eps, paths @ ..] if mode == "floats" => {
+            let eps = eps
+                .parse()
+                .map_err(|err| format!("invalid eps {eps:?}: {err}"))?;
+            (mode.as_str(), eps, paths)
+        }
+        [mode, paths @ ..] if mode == "tokens" || mode == "lines" => (mode.as_str(), 0.0, paths),
+        paths => ("tokens", 0.0, paths),
+    };
+    let [expected, output] = paths else {
+        return Err(USAGE.to_owned());
+    };
+    let mut expected = open(expected).map_err(|err| format!("{expected}: {err}"))?;
+    let mut output = open(output).map_err(|err| format!("{output}: {err}"))?;
+    let verdict = match mode {
+        "lines" => compare_lines(&mut expected, &mut output),
+        "floats" => compare_floats(&mut expected, &mut output, eps),
+        _ => compare_tokens(&mut expected, &mut output),
+    };
+    verdict.map_err(|err| err.to_string())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(verdict) => {
+            println!("{verdict}");
+            if verdict.is_accepted() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    fmt, stdin, stdout, unwrap, BufReadExt, ReadFrom, ReadFromError, StdinStream, StdoutGuard,
+};
+use std::{
+    any::Any,
+    fmt::Display,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Format of the prefix written before each case by [cases], which is `{prefix}{k}{suffix}`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{cases_from, show, CaseFormat, InputStream, ReadInto};
+///
+/// let mut input = InputStream::new("2\n1 2\n3 4\n".as_bytes());
+/// let mut output = Vec::new();
+/// cases_from(&mut input, &mut output, CaseFormat::multiline(), |_, input, out| {
+///     let (a, b): (u32, u32) = input.read();
+///     show!([a, b], sep = "\n" => *out);
+/// });
+/// assert_eq!(String::from_utf8(output).unwrap(), "Case #1:\n1\n2\nCase #2:\n3\n4\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CaseFormat<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a> CaseFormat<'a> {
+    /// Create a format that writes `{prefix}{k}{suffix}` before case `k`.
+    #[inline]
+    pub const fn new(prefix: &'a str, suffix: &'a str) -> Self {
+        Self { prefix, suffix }
+    }
+
+    /// `Case #{k}: `, for answers in a single line.
+    #[inline]
+    pub const fn inline() -> Self {
+        Self::new("Case #", ": ")
+    }
+
+    /// `Case #{k}:` followed by a newline, for answers in multiple lines.
+    #[inline]
+    pub const fn multiline() -> Self {
+        Self::new("Case #", ":\n")
+    }
+
+    /// Get the text before the case number.
+    #[inline]
+    pub const fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Get the text after the case number.
+    #[inline]
+    pub const fn suffix(&self) -> &'a str {
+        self.suffix
+    }
+}
+
+impl Default for CaseFormat<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::inline()
+    }
+}
+
+/// Error during running cases with [try_cases_from].
+#[derive(Debug)]
+pub enum CasesError {
+    /// Error during reading the number of cases.
+    ReadCount(ReadFromError<usize>),
+    /// Error during writing the prefix of a case.
+    IOError(io::Error),
+}
+
+impl Display for CasesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadCount(error) => Display::fmt(error, f),
+            Self::IOError(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for CasesError {}
+
+impl From<io::Error> for CasesError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::IOError(error)
+    }
+}
+
+/// A case that panicked, with its number and the panic payload.
+struct Panicked(usize, Box<dyn Any + Send>);
+
+impl Panicked {
+    /// Panic with the case number and the original message.
+    #[track_caller]
+    fn resume(self) -> ! {
+        let Self(k, payload) = self;
+        panic!("case #{k} panicked: {}", panic_message(&*payload))
+    }
+}
+
+/// Get the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Run all cases, and stop at the first one that panics.
+fn run_cases<S: BufReadExt, W: Write + ?Sized>(
+    input: &mut S,
+    output: &mut W,
+    format: CaseFormat<'_>,
+    mut f: impl FnMut(usize, &mut S, &mut W),
+) -> Result<Result<(), CasesError>, Panicked> {
+    let t = match usize::try_read_from(input, fmt::Default::new()) {
+        Ok(t) => t,
+        Err(error) => return Ok(Err(CasesError::ReadCount(error))),
+    };
+    for k in 1..=t {
+        if let Err(error) = write!(output, "{}{k}{}", format.prefix, format.suffix) {
+            return Ok(Err(error.into()));
+        }
+        panic::catch_unwind(AssertUnwindSafe(|| f(k, input, output)))
+            .map_err(|payload| Panicked(k, payload))?;
+    }
+    Ok(Ok(()))
+}
+
+/// Read the number of cases `T` from `input`, and then for each case `k` in `1..=T`,
+/// write its prefix in `format` into `output` and call `f(k, input, output)`.
+///
+/// # Errors
+///
+/// Returns [CasesError::ReadCount] if reading `T` fails,
+/// and [CasesError::IOError] if writing a prefix fails.
+///
+/// # Panics
+///
+/// If `f` panics, this function panics with the case number and the original message.
+#[inline]
+#[track_caller]
+pub fn try_cases_from<S: BufReadExt, W: Write + ?Sized>(
+    input: &mut S,
+    output: &mut W,
+    format: CaseFormat<'_>,
+    f: impl FnMut(usize, &mut S, &mut W),
+) -> Result<(), CasesError> {
+    run_cases(input, output, format, f).unwrap_or_else(|panicked| panicked.resume())
+}
+
+/// Unwrap the result of [try_cases_from].
+///
+/// # Panics
+///
+/// If reading the number of cases or writing a prefix fails, or if `f` panics.
+#[inline]
+#[track_caller]
+pub fn cases_from<S: BufReadExt, W: Write + ?Sized>(
+    input: &mut S,
+    output: &mut W,
+    format: CaseFormat<'_>,
+    f: impl FnMut(usize, &mut S, &mut W),
+) {
+    unwrap!(try_cases_from(input, output, format, f))
+}
+
+/// Call [try_cases_from] with standard input and output, which are locked until all cases are done.
+///
+/// So read from and write to the given handles instead, for example with
+/// [`read!(...; src = *input)`](crate::read!) and [`show!(... => *out)`](crate::show!).
+///
+/// # Errors
+///
+/// See [try_cases_from].
+///
+/// # Panics
+///
+/// If `f` panics, this function panics with the case number and the original message.
+/// Standard input is unlocked before that, so it is not poisoned.
+#[inline]
+#[track_caller]
+pub fn try_cases_with(
+    format: CaseFormat<'_>,
+    f: impl FnMut(usize, &mut StdinStream, &mut StdoutGuard),
+) -> Result<(), CasesError> {
+    let result = run_cases(&mut *stdin(), &mut stdout(), format, f);
+    result.unwrap_or_else(|panicked| panicked.resume())
+}
+
+/// Unwrap the result of [try_cases_with].
+///
+/// # Panics
+///
+/// If reading the number of cases or writing a prefix fails, or if `f` panics.
+#[inline]
+#[track_caller]
+pub fn cases_with(
+    format: CaseFormat<'_>,
+    f: impl FnMut(usize, &mut StdinStream, &mut StdoutGuard),
+) {
+    unwrap!(try_cases_with(format, f))
+}
+
+/// Call [cases_with] with [CaseFormat::inline], for Code Jam style problems.
+///
+/// ```rust,no_run
+/// use iof::{cases, read, show};
+///
+/// cases(|_, input, out| {
+///     let (a, b): (u32, u32) = read!(; src = *input);
+///     show!(a + b => *out);
+/// });
+/// ```
+///
+/// # Panics
+///
+/// If reading the number of cases or writing a prefix fails, or if `f` panics.
+#[inline]
+#[track_caller]
+pub fn cases(f: impl FnMut(usize, &mut StdinStream, &mut StdoutGuard)) {
+    cases_with(CaseFormat::inline(), f)
+}
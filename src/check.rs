@@ -0,0 +1,220 @@
+//! Output checking, for comparing the output of a solution with the expected output.
+//!
+//! - [compare_tokens] compares whitespace-separated tokens, ignoring whitespace differences;
+//! - [compare_lines] compares line by line, ignoring whitespace differences inside a line and trailing blank lines;
+//! - [compare_floats] compares tokens as [compare_tokens] does, except that real numbers are compared with an absolute or relative error.
+//!
+//! All of them compare `YES` and `NO` case-insensitively, and report the first mismatching token in the output.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iof::{check::{compare_floats, compare_tokens, Verdict}, InputStream};
+//!
+//! let mut expected = InputStream::new("YES\n3.0000\n".as_bytes());
+//! let mut output = InputStream::new("yes 3.000001".as_bytes());
+//! assert!(compare_floats(&mut expected, &mut output, 1e-6).unwrap().is_accepted());
+//!
+//! let mut expected = InputStream::new("1 2 3\n".as_bytes());
+//! let mut output = InputStream::new("1 2\n4\n".as_bytes());
+//! let verdict = compare_tokens(&mut expected, &mut output).unwrap();
+//! assert_eq!(verdict.to_string(), "wrong answer at line 2, token 3: expected \"3\", found \"4\"");
+//! ```
+use crate::{fmt::Default, stream::error::StreamError, BufReadExt, ReadError, ReadOneFrom};
+use std::{
+    fmt::{self, Display},
+    io,
+};
+
+/// The first mismatch between the expected output and the output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Line number of the mismatch in the output, counted from 1.
+    pub line: usize,
+    /// Index of the mismatching token, counted from 1.
+    ///
+    /// It's counted in the whole output for [compare_tokens] and [compare_floats],
+    /// and in the line for [compare_lines].
+    pub token: usize,
+    /// The expected token, or [None] if the output should end.
+    pub expected: Option<String>,
+    /// The token in the output, or [None] if the output ends.
+    pub found: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, token {}: ", self.line, self.token)?;
+        match &self.expected {
+            Some(expected) => write!(f, "expected {expected:?}, ")?,
+            None => f.write_str("expected end of output, ")?,
+        }
+        match &self.found {
+            Some(found) => write!(f, "found {found:?}"),
+            None => f.write_str("found end of output"),
+        }
+    }
+}
+
+/// Result of comparing the output with the expected output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The output matches the expected output.
+    Accepted,
+    /// The output does not match the expected output.
+    WrongAnswer(Mismatch),
+}
+
+impl Verdict {
+    /// Check whether the output is accepted.
+    #[inline]
+    pub const fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Accepted => f.write_str("accepted"),
+            Self::WrongAnswer(mismatch) => write!(f, "wrong answer at {mismatch}"),
+        }
+    }
+}
+
+fn into_io_error(error: StreamError) -> io::Error {
+    match error {
+        StreamError::IOError(error) => error,
+        StreamError::Eof | StreamError::Eol => io::ErrorKind::UnexpectedEof.into(),
+    }
+}
+
+/// Read the next token from `stream`, or [None] at the end of input.
+fn next_token<S: BufReadExt>(stream: &mut S) -> io::Result<Option<String>> {
+    match String::try_read_one_from(stream, Default::new()) {
+        Ok(token) => Ok(Some(token)),
+        Err(ReadError::EOF) => Ok(None),
+        Err(ReadError::IOError(error)) => Err(error),
+        Err(error) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            error.to_string(),
+        )),
+    }
+}
+
+/// Compare two tokens exactly, except that `YES` and `NO` are case-insensitive.
+fn token_eq(expected: &str, found: &str) -> bool {
+    expected == found
+        || ((expected.eq_ignore_ascii_case("yes") || expected.eq_ignore_ascii_case("no"))
+            && expected.eq_ignore_ascii_case(found))
+}
+
+/// Compare two tokens with [token_eq], and compare real numbers with an absolute or relative error `eps`.
+fn float_eq(expected: &str, found: &str, eps: f64) -> bool {
+    match (expected.parse::<f64>(), found.parse::<f64>()) {
+        (Ok(a), Ok(b)) if a.is_finite() && b.is_finite() => {
+            let diff = (a - b).abs();
+            diff <= eps || diff <= eps * a.abs()
+        }
+        (Ok(_), Ok(_)) | (Err(_), Err(_)) => token_eq(expected, found),
+        _ => false,
+    }
+}
+
+fn compare_tokens_by<E: BufReadExt, O: BufReadExt>(
+    expected: &mut E,
+    output: &mut O,
+    mut eq: impl FnMut(&str, &str) -> bool,
+) -> io::Result<Verdict> {
+    let mut token = 0;
+    loop {
+        token += 1;
+        let e = next_token(expected)?;
+        let o = next_token(output)?;
+        let matched = match (&e, &o) {
+            (None, None) => return Ok(Verdict::Accepted),
+            (Some(e), Some(o)) => eq(e, o),
+            _ => false,
+        };
+        if !matched {
+            let line = output.line_number().max(1);
+            return Ok(Verdict::WrongAnswer(Mismatch {
+                line,
+                token,
+                expected: e,
+                found: o,
+            }));
+        }
+    }
+}
+
+/// Compare whitespace-separated tokens in `output` with those in `expected`.
+///
+/// Tokens are compared exactly, except that `YES` and `NO` are case-insensitive.
+#[inline]
+pub fn compare_tokens<E: BufReadExt, O: BufReadExt>(
+    expected: &mut E,
+    output: &mut O,
+) -> io::Result<Verdict> {
+    compare_tokens_by(expected, output, token_eq)
+}
+
+/// Compare whitespace-separated tokens in `output` with those in `expected`,
+/// where real numbers are equal if their absolute or relative error is at most `eps`.
+///
+/// Other tokens are compared as [compare_tokens] does.
+#[inline]
+pub fn compare_floats<E: BufReadExt, O: BufReadExt>(
+    expected: &mut E,
+    output: &mut O,
+    eps: f64,
+) -> io::Result<Verdict> {
+    compare_tokens_by(expected, output, |e, o| float_eq(e, o, eps))
+}
+
+/// Read the next line from `stream`, or [None] at the end of input.
+fn next_line<S: BufReadExt>(stream: &mut S) -> io::Result<Option<String>> {
+    match stream.try_get_line() {
+        Ok(line) => Ok(Some(line.to_owned())),
+        Err(StreamError::Eof) => Ok(None),
+        Err(error) => Err(into_io_error(error)),
+    }
+}
+
+/// Compare `output` with `expected` line by line.
+///
+/// Lines are compared as whitespace-separated tokens with [compare_tokens],
+/// and trailing blank lines are ignored.
+#[inline]
+pub fn compare_lines<E: BufReadExt, O: BufReadExt>(
+    expected: &mut E,
+    output: &mut O,
+) -> io::Result<Verdict> {
+    let mut line = 0;
+    loop {
+        line += 1;
+        let e = next_line(expected)?;
+        let o = next_line(output)?;
+        if e.is_none() && o.is_none() {
+            return Ok(Verdict::Accepted);
+        }
+        let mut e_tokens = e.iter().flat_map(|e| e.split_whitespace());
+        let mut o_tokens = o.iter().flat_map(|o| o.split_whitespace());
+        let mut token = 0;
+        loop {
+            token += 1;
+            match (e_tokens.next(), o_tokens.next()) {
+                (None, None) => break,
+                (Some(e), Some(o)) if token_eq(e, o) => {}
+                (e, o) => {
+                    return Ok(Verdict::WrongAnswer(Mismatch {
+                        line,
+                        token,
+                        expected: e.map(str::to_owned),
+                        found: o.map(str::to_owned),
+                    }))
+                }
+            }
+        }
+    }
+}
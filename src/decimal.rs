@@ -0,0 +1,504 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// A fixed-point decimal number, stored as an integer mantissa and a scale.
+///
+/// The value is `mantissa * 10^-scale`. It is parsed and written exactly, so `12.340` is read as
+/// mantissa `12340` with scale `3`, and written back as `12.340`.
+///
+/// Comparison and hashing are by value, so `1.5 == 1.50`.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{Decimal, Rounding};
+///
+/// let a: Decimal = "12.345".parse().unwrap();
+/// let b: Decimal = "0.655".parse().unwrap();
+/// assert_eq!((a + b).to_string(), "13.000");
+/// assert_eq!(a.round(2, Rounding::HalfEven).to_string(), "12.34");
+/// assert_eq!(a.round(2, Rounding::HalfUp).to_string(), "12.35");
+/// assert_eq!(format!("{:.5}", a), "12.34500");
+/// assert_eq!(a * b, Decimal::new(8085975, 6));
+/// assert!(b < a);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// Rounding mode used by [Decimal::round].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest, and ties to even.
+    #[default]
+    HalfEven,
+    /// Round to the nearest, and ties away from zero.
+    HalfUp,
+    /// Round to the nearest, and ties towards zero.
+    HalfDown,
+    /// Round away from zero.
+    Up,
+    /// Round towards zero, i.e., truncate.
+    Down,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards negative infinity.
+    Floor,
+}
+
+/// Error returned when parsing a [Decimal].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    /// The string is empty or has no digits.
+    Empty,
+    /// The string contains a character that is not allowed.
+    InvalidDigit,
+    /// The mantissa does not fit in [i128].
+    Overflow,
+}
+
+impl fmt::Display for ParseDecimalError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse decimal from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large to fit in target type",
+        })
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+/// Compute `10^exp`, or [None] on overflow.
+#[inline]
+fn pow10(exp: u32) -> Option<i128> {
+    10i128.checked_pow(exp)
+}
+
+/// Compute `mantissa * 10^exp`, or [None] on overflow.
+#[inline]
+fn shift(mantissa: i128, exp: u32) -> Option<i128> {
+    if mantissa == 0 {
+        Some(0)
+    } else {
+        mantissa.checked_mul(pow10(exp)?)
+    }
+}
+
+impl Decimal {
+    /// Zero, with scale `0`.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Create a decimal number `mantissa * 10^-scale`.
+    #[inline]
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Get the mantissa.
+    #[inline]
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Get the scale, i.e., the number of digits after the dot.
+    #[inline]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Remove trailing zeros after the dot.
+    #[inline]
+    pub const fn normalize(self) -> Self {
+        let Self {
+            mut mantissa,
+            mut scale,
+        } = self;
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self { mantissa, scale }
+    }
+
+    /// Round to at most `scale` digits after the dot with `rounding`.
+    ///
+    /// If the number already has no more than `scale` digits after the dot, it is returned as is.
+    pub fn round(self, scale: u32, rounding: Rounding) -> Self {
+        let Some(diff) = self.scale.checked_sub(scale).filter(|&diff| diff > 0) else {
+            return self;
+        };
+        let (quotient, remainder, half) = match pow10(diff) {
+            Some(divisor) => {
+                let remainder = self.mantissa % divisor;
+                let half = (remainder.unsigned_abs() * 2).cmp(&divisor.unsigned_abs());
+                (self.mantissa / divisor, remainder, half)
+            }
+            // The divisor exceeds `2 * i128::MAX`.
+            None => (0, self.mantissa, Ordering::Less),
+        };
+        let away = remainder != 0
+            && match rounding {
+                Rounding::HalfEven => {
+                    half == Ordering::Greater || half == Ordering::Equal && quotient % 2 != 0
+                }
+                Rounding::HalfUp => half != Ordering::Less,
+                Rounding::HalfDown => half == Ordering::Greater,
+                Rounding::Up => true,
+                Rounding::Down => false,
+                Rounding::Ceiling => remainder > 0,
+                Rounding::Floor => remainder < 0,
+            };
+        // `quotient` is at most `i128::MAX / 10` in magnitude, so this never overflows.
+        let mantissa = match (away, remainder > 0) {
+            (false, _) => quotient,
+            (true, true) => quotient + 1,
+            (true, false) => quotient - 1,
+        };
+        Self { mantissa, scale }
+    }
+
+    /// Bring both numbers to the same scale, or [None] on overflow.
+    #[inline]
+    fn align(self, rhs: Self) -> Option<(i128, i128, u32)> {
+        let scale = self.scale.max(rhs.scale);
+        Some((
+            shift(self.mantissa, scale - self.scale)?,
+            shift(rhs.mantissa, scale - rhs.scale)?,
+            scale,
+        ))
+    }
+
+    /// Add two numbers, or [None] on overflow.
+    ///
+    /// The scale of the result is the larger scale of the two.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs, scale) = self.align(rhs)?;
+        Some(Self::new(lhs.checked_add(rhs)?, scale))
+    }
+
+    /// Subtract two numbers, or [None] on overflow.
+    ///
+    /// The scale of the result is the larger scale of the two.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lhs, rhs, scale) = self.align(rhs)?;
+        Some(Self::new(lhs.checked_sub(rhs)?, scale))
+    }
+
+    /// Multiply two numbers, or [None] on overflow.
+    ///
+    /// The scale of the result is the sum of the scales of the two.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.mantissa.checked_mul(rhs.mantissa)?,
+            self.scale.checked_add(rhs.scale)?,
+        ))
+    }
+
+    /// Negate the number, or [None] on overflow.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self::new(self.mantissa.checked_neg()?, self.scale))
+    }
+}
+
+macro_rules! impl_op {
+    ($($trait:ident $method:ident $checked:ident $msg:literal)*) => {
+        $(
+            impl $trait for Decimal {
+                type Output = Self;
+
+                #[inline]
+                #[track_caller]
+                fn $method(self, rhs: Self) -> Self {
+                    match self.$checked(rhs) {
+                        Some(value) => value,
+                        None => panic!($msg),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_op!(
+    Add add checked_add "attempt to add with overflow"
+    Sub sub checked_sub "attempt to subtract with overflow"
+    Mul mul checked_mul "attempt to multiply with overflow"
+);
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self {
+        match self.checked_neg() {
+            Some(value) => value,
+            None => panic!("attempt to negate with overflow"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty)*) => {
+        $(
+            impl From<$ty> for Decimal {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::new(value.into(), 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8 i16 i32 i64 i128 u8 u16 u32 u64);
+
+impl PartialEq for Decimal {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.align(*other) {
+            Some((lhs, rhs, _)) => lhs.cmp(&rhs),
+            // Only the one with the smaller scale can overflow,
+            // and its magnitude is then larger than the other one.
+            None if self.scale < other.scale => self.mantissa.cmp(&0),
+            None => 0.cmp(&other.mantissa),
+        }
+    }
+}
+
+impl Hash for Decimal {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self { mantissa, scale } = self.normalize();
+        mantissa.hash(state);
+        scale.hash(state);
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parse `Sign? (Digit+ | Digit+ '.' Digit* | Digit* '.' Digit+)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let mut mantissa = 0i128;
+        let mut scale = None;
+        let mut any = false;
+        for &byte in digits.as_bytes() {
+            match byte {
+                b'0'..=b'9' => {
+                    let digit = (byte - b'0') as i128;
+                    mantissa = mantissa
+                        .checked_mul(10)
+                        .and_then(|mantissa| {
+                            if negative {
+                                mantissa.checked_sub(digit)
+                            } else {
+                                mantissa.checked_add(digit)
+                            }
+                        })
+                        .ok_or(ParseDecimalError::Overflow)?;
+                    if let Some(scale) = &mut scale {
+                        *scale += 1;
+                    }
+                    any = true;
+                }
+                b'.' if scale.is_none() => scale = Some(0),
+                _ => return Err(ParseDecimalError::InvalidDigit),
+            }
+        }
+        if !any {
+            return Err(if digits.is_empty() {
+                ParseDecimalError::Empty
+            } else {
+                ParseDecimalError::InvalidDigit
+            });
+        }
+        Ok(Self::new(mantissa, scale.unwrap_or(0)))
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// Write the number exactly, with all digits after the dot.
+    ///
+    /// If a precision is given, the number is rounded with [Rounding::HalfEven] or padded with
+    /// zeros to have exactly that many digits after the dot.
+    /// Call [Decimal::round] first for other rounding modes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match f.precision() {
+            Some(precision) => self.round(precision as u32, Rounding::default()),
+            None => *self,
+        };
+        let digits = value.mantissa.unsigned_abs().to_string();
+        let scale = value.scale as usize;
+        let padding = f
+            .precision()
+            .map_or(0, |precision| precision - scale.min(precision));
+
+        let mut s = String::with_capacity(digits.len() + scale + padding + 2);
+        if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            s.push_str(integer);
+            s.push('.');
+            s.push_str(fraction);
+        } else {
+            s.push_str("0.");
+            s.extend(std::iter::repeat_n('0', scale - digits.len()));
+            s.push_str(&digits);
+        }
+        s.extend(std::iter::repeat_n('0', padding));
+        if s.ends_with('.') {
+            let _: Option<char> = s.pop();
+        }
+        f.pad_integral(value.mantissa >= 0, "", &s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decimal, ParseDecimalError, Rounding};
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(d("12.340").mantissa(), 12340);
+        assert_eq!(d("12.340").scale(), 3);
+        assert_eq!(d("-.5"), Decimal::new(-5, 1));
+        assert_eq!(d("+7."), Decimal::new(7, 0));
+        assert_eq!(
+            d("-170141183460469231731687303715884105728"),
+            Decimal::new(i128::MIN, 0),
+        );
+        assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!("-".parse::<Decimal>(), Err(ParseDecimalError::Empty));
+        assert_eq!(".".parse::<Decimal>(), Err(ParseDecimalError::InvalidDigit));
+        assert_eq!(
+            "1.2.3".parse::<Decimal>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            "1e5".parse::<Decimal>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            "170141183460469231731687303715884105728".parse::<Decimal>(),
+            Err(ParseDecimalError::Overflow),
+        );
+    }
+
+    #[test]
+    fn display() {
+        for s in ["0", "0.000", "-0.05", "12.340", "-1", "100", ".5", "-3."] {
+            let value = d(s);
+            assert_eq!(value, d(&value.to_string()));
+        }
+        assert_eq!(d("-0.05").to_string(), "-0.05");
+        assert_eq!(d(".5").to_string(), "0.5");
+        assert_eq!(d("-3.").to_string(), "-3");
+        assert_eq!(format!("{:.1}", d("0.25")), "0.2");
+        assert_eq!(format!("{:.3}", d("-0.25")), "-0.250");
+        assert_eq!(format!("{:.0}", d("2.5")), "2");
+        assert_eq!(format!("{:+08.2}", d("3.14159")), "+0003.14");
+    }
+
+    #[test]
+    fn round() {
+        let cases = [
+            ("2.5", [2, 3, 2, 3, 2, 3, 2]),
+            ("-2.5", [-2, -3, -2, -3, -2, -2, -3]),
+            ("2.51", [3, 3, 3, 3, 2, 3, 2]),
+            ("-2.49", [-2, -2, -2, -3, -2, -2, -3]),
+            ("3.5", [4, 4, 3, 4, 3, 4, 3]),
+            ("4.0", [4, 4, 4, 4, 4, 4, 4]),
+        ];
+        let modes = [
+            Rounding::HalfEven,
+            Rounding::HalfUp,
+            Rounding::HalfDown,
+            Rounding::Up,
+            Rounding::Down,
+            Rounding::Ceiling,
+            Rounding::Floor,
+        ];
+        for (s, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(d(s).round(0, mode), Decimal::from(expected), "{s} {mode:?}");
+            }
+        }
+        assert_eq!(d("1.5").round(3, Rounding::Down).scale(), 1);
+        assert_eq!(
+            Decimal::new(i128::MAX, 50).round(0, Rounding::Up),
+            Decimal::from(1)
+        );
+        assert_eq!(
+            Decimal::new(i128::MIN, 39).round(0, Rounding::HalfUp),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(d("1.5") + d("2.25"), d("3.75"));
+        assert_eq!((d("1.5") + d("2.25")).scale(), 2);
+        assert_eq!(d("1.5") - d("2.25"), d("-0.75"));
+        assert_eq!(d("1.5") * d("-2.25"), d("-3.375"));
+        assert_eq!(-d("1.5"), d("-1.5"));
+        assert_eq!(
+            Decimal::new(i128::MAX, 0).checked_add(Decimal::from(1)),
+            None
+        );
+        assert_eq!(Decimal::new(1, 0).checked_add(Decimal::new(1, 40)), None);
+        assert_eq!(Decimal::new(i128::MIN, 0).checked_neg(), None);
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(d("1.5"), d("1.500"));
+        assert!(d("1.49") < d("1.5"));
+        assert!(d("-1.5") < d("-1.49"));
+        assert!(Decimal::new(1, 0) > Decimal::new(i128::MAX, 40));
+        assert!(Decimal::new(-1, 0) < Decimal::new(i128::MIN, 40));
+        assert!(Decimal::new(i128::MAX, 40) < Decimal::new(1, 0));
+        assert_eq!(Decimal::new(0, 0), Decimal::new(0, 100));
+
+        let set = std::collections::HashSet::from([d("1.5"), d("1.50"), d("1.500")]);
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,378 @@
+//! Random test data generation, for writing inputs of stress tests.
+//!
+//! [Rng] is a small seeded pseudo-random number generator, so the same seed always generates the same data.
+//! Generated data are plain [Vec]s, [String]s and tuples, so they can be written with [WriteInto](crate::WriteInto)
+//! or [show!](crate::show!) in the default format, and read back with [read!](crate::read!).
+//!
+//! Vertices of trees and graphs are counted from 1, as is common in problem inputs.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iof::{gen::Rng, InputStream, ReadInto, WriteInto};
+//!
+//! let mut rng = Rng::new(42);
+//! let n = rng.int(2..=10usize);
+//! let a: Vec<i32> = rng.ints(n, -100..=100);
+//! let edges = rng.tree_edges(n);
+//!
+//! let input = format!(
+//!     "{}\n{}\n{}\n",
+//!     n,
+//!     a.try_write_into_string().unwrap(),
+//!     edges.try_write_into_string().unwrap(),
+//! );
+//! let mut reader = InputStream::new(input.as_bytes());
+//! let m: usize = reader.read();
+//! let b: Vec<i32> = reader.read_n(m);
+//! let e: Vec<(usize, usize)> = reader.read_n(m - 1);
+//! assert_eq!((m, b, e), (n, a, edges));
+//! ```
+use std::{
+    collections::HashSet,
+    ops::{Range, RangeInclusive},
+};
+
+/// Integers that can be generated by [Rng].
+///
+/// Each integer is mapped into a [u64] in an order-preserving way.
+pub trait RandomInt: Copy {
+    /// Map `self` into a [u64], preserving the order.
+    fn to_ordered(self) -> u64;
+    /// Map a [u64] back into `Self`, which is the inverse of [RandomInt::to_ordered].
+    fn from_ordered(x: u64) -> Self;
+}
+
+macro_rules! impl_random_int {
+    (unsigned $($t:ty)*) => {
+        $(
+            impl RandomInt for $t {
+                #[inline]
+                fn to_ordered(self) -> u64 {
+                    self as u64
+                }
+                #[inline]
+                fn from_ordered(x: u64) -> Self {
+                    x as Self
+                }
+            }
+        )*
+    };
+    (signed $($t:ty)*) => {
+        $(
+            impl RandomInt for $t {
+                #[inline]
+                fn to_ordered(self) -> u64 {
+                    (self as i64 as u64) ^ (1 << 63)
+                }
+                #[inline]
+                fn from_ordered(x: u64) -> Self {
+                    (x ^ (1 << 63)) as i64 as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_random_int!(unsigned u8 u16 u32 u64 usize);
+impl_random_int!(signed i8 i16 i32 i64 isize);
+
+/// Ranges of integers that can be sampled by [Rng].
+pub trait SampleRange<T: RandomInt> {
+    /// Get the inclusive bounds of the range, mapped by [RandomInt::to_ordered].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    fn bounds(self) -> (u64, u64);
+}
+
+impl<T: RandomInt> SampleRange<T> for Range<T> {
+    #[inline]
+    fn bounds(self) -> (u64, u64) {
+        let (lo, hi) = (self.start.to_ordered(), self.end.to_ordered());
+        assert!(lo < hi, "cannot sample from an empty range");
+        (lo, hi - 1)
+    }
+}
+
+impl<T: RandomInt> SampleRange<T> for RangeInclusive<T> {
+    #[inline]
+    fn bounds(self) -> (u64, u64) {
+        let (lo, hi) = (self.start().to_ordered(), self.end().to_ordered());
+        assert!(lo <= hi, "cannot sample from an empty range");
+        (lo, hi)
+    }
+}
+
+/// A seeded pseudo-random number generator, based on SplitMix64.
+///
+/// It's fast and good enough for generating test data, but not for cryptography.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create a generator from `seed`.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generate a uniformly distributed [u64].
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a uniformly distributed integer in `0..n`, where `n` is positive.
+    fn below(&mut self, n: u64) -> u64 {
+        debug_assert!(n > 0);
+        // Reject the lowest `2^64 % n` values, so that the rest is a multiple of `n`.
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return x % n;
+            }
+        }
+    }
+
+    /// Generate a uniformly distributed index in `0..n`.
+    fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    /// Generate a uniformly distributed integer in `lo..=hi`.
+    fn between(&mut self, lo: u64, hi: u64) -> u64 {
+        match (hi - lo).checked_add(1) {
+            Some(n) => lo + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    /// Generate a uniformly distributed integer in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    #[inline]
+    pub fn int<T: RandomInt>(&mut self, range: impl SampleRange<T>) -> T {
+        let (lo, hi) = range.bounds();
+        T::from_ordered(self.between(lo, hi))
+    }
+
+    /// Generate `n` integers in `range` independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    #[inline]
+    pub fn ints<T: RandomInt>(&mut self, n: usize, range: impl SampleRange<T>) -> Vec<T> {
+        let (lo, hi) = range.bounds();
+        (0..n)
+            .map(|_| T::from_ordered(self.between(lo, hi)))
+            .collect()
+    }
+
+    /// Generate `n` distinct integers in `range`, in random order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` has fewer than `n` integers.
+    #[inline]
+    pub fn distinct<T: RandomInt>(&mut self, n: usize, range: impl SampleRange<T>) -> Vec<T> {
+        let (lo, hi) = range.bounds();
+        let span = (hi - lo).checked_add(1);
+        assert!(
+            span.is_none_or(|span| n as u64 <= span),
+            "cannot generate {n} distinct integers from a range of {} integers",
+            span.unwrap_or_default(),
+        );
+        let span = span.unwrap_or(u64::MAX);
+        // Floyd's algorithm.
+        let mut chosen = HashSet::with_capacity(n);
+        let mut result = Vec::with_capacity(n);
+        for j in span - n as u64..span {
+            let t = self.below(j + 1);
+            let x = if chosen.insert(t) {
+                t
+            } else {
+                let _: bool = chosen.insert(j);
+                j
+            };
+            result.push(T::from_ordered(lo + x));
+        }
+        self.shuffle(&mut result);
+        result
+    }
+
+    /// Shuffle `slice` uniformly.
+    #[inline]
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.index(i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Generate a uniformly distributed permutation of `1..=n`.
+    #[inline]
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut p: Vec<usize> = (1..=n).collect();
+        self.shuffle(&mut p);
+        p
+    }
+
+    /// Generate a string of `n` characters chosen uniformly from `alphabet`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alphabet` is empty and `n` is positive.
+    #[inline]
+    pub fn string(&mut self, n: usize, alphabet: &str) -> String {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        assert!(
+            n == 0 || !alphabet.is_empty(),
+            "cannot generate a string from an empty alphabet",
+        );
+        (0..n)
+            .map(|_| alphabet[self.index(alphabet.len())])
+            .collect()
+    }
+
+    /// Generate a random tree on vertices `1..=n` as a parent array,
+    /// where the `i`-th element is the parent of vertex `i + 2`.
+    ///
+    /// The parent of each vertex `v` is chosen uniformly from `1..v`, so vertex 1 is the root.
+    #[inline]
+    pub fn tree_parents(&mut self, n: usize) -> Vec<usize> {
+        (2..=n).map(|v| 1 + self.index(v - 1)).collect()
+    }
+
+    /// Generate a uniformly distributed labelled tree on vertices `1..=n` as `n - 1` edges, in random order.
+    #[inline]
+    pub fn tree_edges(&mut self, n: usize) -> Vec<(usize, usize)> {
+        if n < 2 {
+            return Vec::new();
+        }
+        // Decode a random Prüfer sequence.
+        let prufer: Vec<usize> = (0..n - 2).map(|_| self.index(n)).collect();
+        let mut degree = vec![1; n];
+        for &v in &prufer {
+            degree[v] += 1;
+        }
+        let mut edges = Vec::with_capacity(n - 1);
+        let mut ptr = degree.iter().position(|&d| d == 1).unwrap_or_default();
+        let mut leaf = ptr;
+        for &v in &prufer {
+            edges.push((leaf + 1, v + 1));
+            degree[v] -= 1;
+            if degree[v] == 1 && v < ptr {
+                leaf = v;
+            } else {
+                ptr += 1;
+                while degree[ptr] != 1 {
+                    ptr += 1;
+                }
+                leaf = ptr;
+            }
+        }
+        edges.push((leaf + 1, n));
+        for edge in &mut edges {
+            if self.next_u64() & 1 == 1 {
+                *edge = (edge.1, edge.0);
+            }
+        }
+        self.shuffle(&mut edges);
+        edges
+    }
+
+    /// Generate `m` distinct unordered pairs `(a, b)` with `a < b` in `0..n`, excluding those in `used`.
+    fn pairs(
+        &mut self,
+        n: usize,
+        m: usize,
+        mut used: HashSet<(usize, usize)>,
+    ) -> Vec<(usize, usize)> {
+        let total = n * n.saturating_sub(1) / 2;
+        assert!(
+            used.len() + m <= total,
+            "cannot generate {m} more edges on {n} vertices",
+        );
+        if (used.len() + m) * 2 > total {
+            let mut rest: Vec<(usize, usize)> = (0..n)
+                .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                .filter(|pair| !used.contains(pair))
+                .collect();
+            self.shuffle(&mut rest);
+            rest.truncate(m);
+            return rest;
+        }
+        let mut pairs = Vec::with_capacity(m);
+        while pairs.len() < m {
+            let (a, b) = (self.index(n), self.index(n));
+            if a == b {
+                continue;
+            }
+            let pair = (a.min(b), a.max(b));
+            if used.insert(pair) {
+                pairs.push(pair);
+            }
+        }
+        pairs
+    }
+
+    /// Generate a connected simple graph on vertices `1..=n` with `m` edges, in random order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is less than `n - 1` or greater than `n * (n - 1) / 2`.
+    #[inline]
+    pub fn connected_graph(&mut self, n: usize, m: usize) -> Vec<(usize, usize)> {
+        let tree = self.tree_edges(n);
+        assert!(
+            m >= tree.len(),
+            "a connected graph on {n} vertices needs at least {} edges",
+            tree.len(),
+        );
+        let used: HashSet<_> = tree
+            .iter()
+            .map(|&(a, b)| (a.min(b) - 1, a.max(b) - 1))
+            .collect();
+        let mut edges = tree;
+        for (a, b) in self.pairs(n, m - edges.len(), used) {
+            edges.push(if self.next_u64() & 1 == 1 {
+                (a + 1, b + 1)
+            } else {
+                (b + 1, a + 1)
+            });
+        }
+        self.shuffle(&mut edges);
+        edges
+    }
+
+    /// Generate a directed acyclic graph on vertices `1..=n` with `m` distinct edges, in random order.
+    ///
+    /// The vertices are in a random topological order, rather than in `1..=n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is greater than `n * (n - 1) / 2`.
+    #[inline]
+    pub fn dag(&mut self, n: usize, m: usize) -> Vec<(usize, usize)> {
+        let order = self.permutation(n);
+        let mut edges: Vec<_> = self
+            .pairs(n, m, HashSet::new())
+            .into_iter()
+            .map(|(a, b)| (order[a], order[b]))
+            .collect();
+        self.shuffle(&mut edges);
+        edges
+    }
+}
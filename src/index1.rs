@@ -0,0 +1,138 @@
+use std::{error::Error, fmt, num::ParseIntError, str::FromStr};
+
+/// A 1-based index in input and output, which is stored 0-based.
+///
+/// One is subtracted when reading, and added back when writing, so that internal indices
+/// can be used directly while input and output count from 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{show, Index1, InputStream, ReadInto, Usize1};
+///
+/// let mut reader = InputStream::new("3\n1 2\n2 3\n".as_bytes());
+/// let n: usize = reader.read();
+/// let edges: Vec<(Usize1, Usize1)> = reader.read_n(n - 1);
+/// assert_eq!(edges, [(Index1(0), Index1(1)), (Index1(1), Index1(2))]);
+///
+/// let mut out = Vec::new();
+/// show!(edges[1] => out);
+/// assert_eq!(String::from_utf8(out).unwrap(), "2 3\n");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Index1<T>(pub T);
+
+/// A 1-based [usize] index, such as a vertex id.
+pub type Usize1 = Index1<usize>;
+
+/// A 1-based [isize] index, which may be negative but never `0`.
+///
+/// Negative indices are shifted as well, so `-3` is read as `-4`.
+/// Note that `Index1(-1)` is written as `0`, which cannot be read back.
+pub type Isize1 = Index1<isize>;
+
+/// Error during parsing an [Index1].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIndex1Error {
+    /// The string is not a valid integer.
+    Int(ParseIntError),
+    /// The integer is `0`, which is not a 1-based index.
+    Zero,
+    /// The integer is the minimum value of a signed type, so it cannot be shifted to 0-based.
+    Underflow,
+}
+
+impl fmt::Display for ParseIndex1Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(err) => fmt::Display::fmt(err, f),
+            Self::Zero => f.write_str("1-based index cannot be 0"),
+            Self::Underflow => f.write_str("1-based index too small to be shifted to 0-based"),
+        }
+    }
+}
+
+impl Error for ParseIndex1Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Int(err) => Some(err),
+            Self::Zero | Self::Underflow => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseIndex1Error {
+    #[inline]
+    fn from(err: ParseIntError) -> Self {
+        Self::Int(err)
+    }
+}
+
+macro_rules! impl_index1 {
+    (@from_str $ty:ty) => {
+        impl FromStr for Index1<$ty> {
+            type Err = ParseIndex1Error;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let index: $ty = s.parse()?;
+                if index == 0 {
+                    return Err(ParseIndex1Error::Zero);
+                }
+                index.checked_sub(1).map(Self).ok_or(ParseIndex1Error::Underflow)
+            }
+        }
+    };
+    (@max $($ty:ty => $max_plus_one:literal)*) => {
+        $(
+            impl_index1!(@from_str $ty);
+
+            /// Write the index plus one, and the maximum value as the literal after it,
+            /// as there is no wider type.
+            impl fmt::Display for Index1<$ty> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match self.0.checked_add(1) {
+                        Some(index) => fmt::Display::fmt(&index, f),
+                        None => f.pad_integral(true, "", $max_plus_one),
+                    }
+                }
+            }
+        )*
+    };
+    ($($ty:ty => $wide:ty)*) => {
+        $(
+            impl_index1!(@from_str $ty);
+
+            /// Write the index plus one, which never overflows.
+            impl fmt::Display for Index1<$ty> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&(self.0 as $wide + 1), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_index1!(
+    i8 => i16
+    i16 => i32
+    i32 => i64
+    i64 => i128
+    isize => i128
+
+    u8 => u16
+    u16 => u32
+    u32 => u64
+    u64 => u128
+    usize => u128
+);
+
+impl_index1!(
+    @max
+    i128 => "170141183460469231731687303715884105728"
+    u128 => "340282366920938463463374607431768211456"
+);
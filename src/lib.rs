@@ -52,7 +52,7 @@
 //!
 //! - `read!()` reads a single data item from input.
 //! - `read!(n)` reads `n` data items from input and stores them in a [Vec].
-//! - `read!(m, n)` reads `m * n` data items from input and stores them in a [Mat].
+//! - `read!(m, n)` reads `m * n` data items from input and stores them in a [Vec] of [Vec]s, and `read!(mat m, n)` stores them in a [Mat].
 //!
 //! Given the input below:
 //!
@@ -130,17 +130,35 @@
 //! Some higher-level functions are provided to read data sequence (a single item is also a sequence) from input:
 //!
 //! - [`read<T>()`](read()) (or [`try_read<T>()`](try_read())) reads a single sequence from input and converts it to a value of `T`.
+//! - [`read_columns<T>(n)`](read_columns()) (or [`try_read_columns<T>(n)`](try_read_columns())) reads `n` sequences of tuple `T` from input, and converts them to a tuple of [Vec]s, one for each element of `T`. See [ReadColumns].
+//! - [`read_into_slice<T>(dest)`](read_into_slice()), [`read_extend<T>(dest, n)`](read_extend()) and [`read_into_mat<T>(dest, m, n)`](read_into_mat()) (or their `try_` versions) read into existing buffers and reuse their capacity, and [ReadOneFrom::parse_into] reuses the buffer of [String] and [ASCIIString].
+//! - [`read_opt<T>()`](read_opt()) (or [`try_read_opt<T>()`](try_read_opt())) reads a single sequence from input like [`read<T>()`](read()), but returns [None] if the end of input is reached before the sequence starts, which is handy for reading until the end of input.
 //! - [`read_n<T>(n)`](read_n()) (or [`try_read_n<T>(n)`](try_read_n())) reads `n` sequences from input and converts them to a value of [Vec].
 //! - [`read_m_n<T>(m, n)`](read_m_n()) (or [`try_read_m_n<T>(m, n)`](try_read_m_n())) reads `m * n` sequences from input and converts them to a value of [`Mat<T>`].
+//! - [`read_fixed<T>(layout)`](read_fixed()) (or [`try_read_fixed<T>(layout)`](try_read_fixed())) reads a non-empty line of fixed-width columns described by a [FixedWidth] layout, and parses each field into an element of `T`, such as a tuple. See [ReadFixed].
+//! - [`read_grid<T>(n)`](read_grid()) (or [`try_read_grid<T>(n)`](try_read_grid())) reads `n` lines as rows of a character grid of [u8], [ASCIIChar] or [char], and converts them to a value of [`Mat<T>`]. [`read_grid_ragged<T>(n)`](read_grid_ragged()) allows rows of different widths, and [`read_grid_map(n, f)`](read_grid_map()) maps each cell with `f`. See [GridCell].
+//! - [`read_graph(n, m, layout)`](read_graph()) (or [`try_read_graph(n, m, layout)`](try_read_graph())) reads `m` edges of a graph with `n` vertices in a [GraphLayout] into adjacency lists, and [`read_weighted_graph<W>(n, m, layout)`](read_weighted_graph()) reads a weight of type `W` after each edge. [`read_csr_graph(n, m, layout)`](read_csr_graph()) and [`read_weighted_csr_graph<W>(n, m, layout)`](read_weighted_csr_graph()) read them into a compact [Csr] graph, and [`read_tree_parents(n, layout)`](read_tree_parents()) reads a tree given as a parent array. Vertex ids out of range are reported with their line numbers. See [ReadGraph].
+//! - [`read_blocks<T>()`](read_blocks()) (or [`iter_blocks<T>()`](iter_blocks())) reads groups of non-blank lines separated by blank lines, and parses each group into a value of `T`. See [BufReadExt::try_get_block].
+//! - [`read_until<T>(sentinel)`](read_until()) (or [`try_read_until<T>(sentinel)`](try_read_until())) reads sequences until `sentinel` and converts them to a value of [Vec], and [`read_while<T>(pred)`](read_while()) (or [`try_read_while<T>(pred)`](try_read_while())) reads sequences while they satisfy `pred`. The sentinel, or the first sequence that does not satisfy `pred`, is consumed but not returned. [`iter_until`] and [`iter_while`] do the same lazily.
 //!
 //! These functions are implemented for types that implement [ReadInto] trait. Currently, the following types implement [ReadInto] trait:
 //!
 //! - All types that implement [ReadOneFrom] trait;
 //! - `[T; N]` where `T` implements [ReadInto] trait;
 //! - `Box<[T; N]>` where `T` implements [ReadInto] trait.
+//! - [`Option<T>`] where `T` implements [ReadInto] trait, which reads [None] if there are no more tokens in current line.
 //! - Tuple types, e.g., `(T1, T2, ..., Tn)`, where `Ti` implements [ReadInto] trait and `n` is neither 0 nor more than 12.
+//! - [VecDeque], [BinaryHeap], [HashSet] and [BTreeSet] of `T`, and [HashMap] and [BTreeMap] of `(K, V)` pairs, where `T`, `K` and `V` implement [ReadInto] trait. See [ReadCollection] for more options.
+//! - [`LenPrefixed<C>`](LenPrefixed) where `C` implements [ReadCollection] trait, such as [`LenPrefixed<Vec<T>>`](LenPrefixed) and [`LenPrefixed<String>`](LenPrefixed), which reads a length and then that many elements.
 //! - ...
 //!
+//! [VecDeque]: std::collections::VecDeque
+//! [BinaryHeap]: std::collections::BinaryHeap
+//! [HashSet]: std::collections::HashSet
+//! [BTreeSet]: std::collections::BTreeSet
+//! [HashMap]: std::collections::HashMap
+//! [BTreeMap]: std::collections::BTreeMap
+//!
 //! ## [ReadOneFrom]
 //!
 //! Some lower-level functions are provided to read a single data item from input:
@@ -220,6 +238,8 @@
 //! - [`read_all<T>()`] (or [`try_read_all<T>()`]) reads all remaining data items from input and converts them to a value of [Vec].
 //! - [`read_any_in_line<T>()`] (or [`try_read_any_in_line<T>()`]) reads all data items in current line from input and converts them to a value of [Vec].
 //! - [`read_some_in_line<T>()`] (or [`try_read_some_in_line<T>()`]) reads all data items in the next non-empty line from input and converts them to a value of [Vec].
+//! - [`read_line_of<T>(n)`](read_line_of()) (or [`try_read_line_of<T>(n)`](try_read_line_of())) reads exactly `n` data items in the next non-empty line from input, and [`read_rows<T>(m, n)`](read_rows()) (or [`try_read_rows<T>(m, n)`](try_read_rows())) reads `m` such lines into a [`Mat<T>`]. Unlike [`read_m_n<T>(m, n)`](read_m_n()), they return [ReadError::TokenCount] if a line has a different number of data items.
+//! - [`read_mat_auto<T>()`] (or [`try_read_mat_auto<T>()`]) reads lines of data items until the end of input or a blank line, and converts them to a value of [`Mat<T>`], whose number of columns is inferred from the first line.
 //!
 //! These functions are implemented for types that implement [ReadOneFrom] trait. Currently, the following types in [std] (or [core]) implement [ReadOneFrom] trait:
 //!
@@ -233,6 +253,11 @@
 //! - [NonZeroI8], [NonZeroI16], [NonZeroI32], [NonZeroI64], [NonZeroI128], [NonZeroIsize];
 //! - ...
 //!
+//! Integers in other radixes can be read with wrappers in [radix], such as [`Hex<T>`](radix::Hex) and [`AutoRadix<T>`](radix::AutoRadix).
+//! 1-based indices can be read as 0-based ones with [Usize1], [Isize1] or [`Index1<T>`](Index1), which add one back when written.
+//! Decimal numbers can be read exactly with [Decimal], which is a fixed-point number.
+//! Integers of arbitrary length can be read with [BigUint] and [BigInt], or as their digits with [Digits].
+//!
 //! And you can implement [ReadOneFrom] trait for your own types by implementing [ReadOneFrom::parse] method. For [FromStr] types, you can use the macro [impl_read_one_from_for_from_str!].
 //!
 //! [FromStr]: std::str::FromStr
@@ -360,6 +385,9 @@
 //!    - For [u8], [u16], [u32], [u64], [u128], [usize], [i8], [i16], [i32], [i64], [i128], [isize], [f32], [f64], [bool], [NonZeroU8], [NonZeroU16], [NonZeroU32], [NonZeroU64], [NonZeroU128], [NonZeroUsize], [NonZeroI8], [NonZeroI16], [NonZeroI32], [NonZeroI64], [NonZeroI128], [NonZeroIsize], it writes the value as is in decimal format;
 //! - For `[T]`, `[T; N]` and [Vec] where `T` implements [WriteInto] trait, it writes each item in the vector with a space as separator;
 //! - For [Mat] where `T` implements [WriteInto] trait, it writes each row in the matrix with a newline as separator, and writes each item in a row with a space as separator;
+//! - For [HashMap] and [BTreeMap] where `K` and `V` implement [WriteInto] trait, it writes each `k v` pair with a newline as separator, and writes the key and the value with a space as separator. Wrap a [HashMap] in [Sorted] to write it in the order of keys;
+//! - For [Fixed] with a [FixedWidth] layout and a value that implements [WriteFixed] trait, such as a tuple, it pads and aligns each element into its field;
+//! - For [Grid] of a [Mat] or rows of cells that implement [GridCell] trait, it writes each row with a newline as separator, and writes cells without separators;
 //! - For all `&T` where `T` implements [WriteInto] trait, it writes the value as is.
 //!
 //! And you can implement [WriteInto] trait for your own types by implementing [WriteInto::try_write_into_with_sep] method. For [Display] types, you can use the macro [impl_write_into_for_display!].
@@ -395,6 +423,26 @@
 //!
 //! The dimension of a type is the number of dimensions of the data sequence. For example, the dimension of a primitive type `T` is 0, the dimension of [`Vec<T>`] is 1, and the dimension of [`Mat<T>`] is 2.
 //!
+//! ## Multiple Test Cases
+//!
+//! For problems whose input starts with the number of test cases `T`, use [cases] (or [cases_with] and [cases_from]). It reads `T`, writes a prefix such as `Case #k: ` in a [CaseFormat] before each case, and calls your closure with the case number and handles of input and output. If a case panics, the panic is reported with its case number.
+//!
+//! ## Validation
+//!
+//! To check that test data follow an exact format, wrap an input stream in [validate::StrictStream]. Every space, end of line and end of file must be read explicitly with [`read_space()`](validate::StrictStream::read_space), [`read_eoln()`](validate::StrictStream::read_eoln) and [`read_eof()`](validate::StrictStream::read_eof), and numbers are read with [`read_int_in(range, name)`](validate::StrictStream::read_int_in) and [`read_real_in(range, digits, name)`](validate::StrictStream::read_real_in), which reject leading zeros, `+` signs and values out of range. Failures are reported with names and positions.
+//!
+//! ## Checking
+//!
+//! To compare the output of a solution with the expected output, use [check::compare_tokens], [check::compare_lines] or [check::compare_floats]. They ignore whitespace differences, compare `YES` and `NO` case-insensitively, and return a [check::Verdict] with the first mismatching token. The `iof-check` binary wraps them for judges.
+//!
+//! ## Generating
+//!
+//! To generate random inputs for stress tests, use [gen::Rng], a seeded pseudo-random number generator with generators for integers in ranges, distinct integers, permutations, strings over an alphabet, trees, connected graphs and DAGs. The generated data can be written with [show!] and read back with [read!].
+//!
+//! ## Stress Testing
+//!
+//! To compare a fast solution with a brute-force one on random inputs, use [stress] (or [try_stress]). Each input is generated with a seeded [gen::Rng], and both solutions are run with [stdin] and [stdout] redirected in current thread, so they can use [read!] and [show!] as usual. It stops on the first input on which their outputs differ, and [stress_shrinking] (or [try_stress_shrinking]) further shrinks that input with [Shrink].
+//!
 //! # Notes
 //!
 //! ## Concurrency
@@ -420,25 +468,44 @@
 //! If you call [`read_one<String>()`] for 3 times and [`read_in_line_trimmed<String>()`] for 1 time, they will read `1`, `2`, `3`, and an empty string respectively. Therefore it's generally unrecommended to use [`read_in_line_trimmed<String>()`] and similar functions that read a possibly empty line of string without specifying the number of data items to read.
 pub use crate as iof;
 pub use ascii::{char::Char as ASCIIChar, string::String as ASCIIString};
+pub use bigint::{BigInt, BigUint, Digits, ParseBigIntError};
+pub use cases::{
+    cases, cases_from, cases_with, try_cases_from, try_cases_with, CaseFormat, CasesError,
+};
+pub use decimal::{Decimal, ParseDecimalError, Rounding};
 pub use formatted::SepBy;
+pub use index1::{Index1, Isize1, ParseIndex1Error, Usize1};
 pub use mat::Mat;
 pub use read::{
+    collection::{Duplicate, ReadCollection},
+    columns::{Columns, ReadColumns},
     error::ReadError,
+    fixed::{Align, FixedWidth, ReadFixed, WidthUnit},
     fmt,
+    graph::{Csr, EdgeError, GraphLayout, ReadGraph, WeightedEdgeError},
+    grid::GridCell,
+    len_prefixed::{LenPrefixed, LenPrefixedError},
     read_from::{ReadFrom, ReadFromError},
     read_into::ReadInto,
     read_one_from::{ReadOneFrom, ReadOneFromError},
     read_one_into::ReadOneInto,
 };
-pub use stdio::{read_into::*, stdin, stdout, stream::*};
+pub use stdio::{read_into::*, stdin, stdout, stream::*, StdinGuard, StdinStream, StdoutGuard};
 pub use stream::{
     ext,
     input_stream::InputStream,
     traits::{BufReadExt, BufReadExtWithFormat},
 };
+pub use stress::{
+    stress, stress_shrinking, try_stress, try_stress_shrinking, Counterexample, Shrink, StressError,
+};
 pub use write::{
-    dimension, separator,
+    dimension,
+    fixed::{Fixed, WriteFixed},
+    grid::Grid,
+    separator,
     separators::{DefaultSeparator, Separators},
+    sorted::Sorted,
     writer::write,
     WriteInto,
 };
@@ -446,12 +513,21 @@ pub use Vec;
 
 mod array;
 pub mod ascii;
+mod bigint;
+mod cases;
+pub mod check;
+mod decimal;
 mod formatted;
-mod mat;
+pub mod gen;
+mod index1;
+pub mod mat;
+pub mod radix;
 mod read;
 mod stdio;
 pub(crate) mod stream;
+mod stress;
 pub mod utf8char;
+pub mod validate;
 mod write;
 
 /// Unwrap a result or panic with the error message.
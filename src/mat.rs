@@ -1,5 +1,16 @@
+//! A matrix with flat row-major storage, and iterators over it.
+use std::{
+    fmt,
+    iter::FusedIterator,
+    mem::take,
+    ops::{Index, IndexMut},
+};
+
 /// A matrix with `m` rows and `n` columns.
 ///
+/// Elements are stored in a single [Vec] in row-major order,
+/// so all rows have the same length.
+///
 /// # Examples
 ///
 /// Create a matrix with two rows and three columns:
@@ -9,12 +20,516 @@
 /// let mat = Mat::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
 /// assert_eq!(mat[0], [1, 2, 3]);
 /// assert_eq!(mat[1], [4, 5, 6]);
+/// assert_eq!(mat[(1, 2)], 6);
+/// assert_eq!((mat.rows(), mat.cols()), (2, 3));
+/// assert_eq!(mat.col(1).copied().collect::<Vec<_>>(), [2, 5]);
+/// assert_eq!(mat.transpose(), [[1, 4], [2, 5], [3, 6]]);
 /// ```
 ///
 /// Read a matrix with two rows and three columns:
 ///
 /// ```rust,no_run
 /// use iof::{read, Mat};
-/// let _: Mat<i32> = read!(2, 3);
+/// let _: Mat<i32> = read!(mat 2, 3);
 /// ```
-pub type Mat<T> = Vec<Vec<T>>;
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Mat<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Mat<T> {
+    /// Create an empty matrix with no rows.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            rows: 0,
+            cols: 0,
+        }
+    }
+
+    /// Create a matrix with `rows` rows and `cols` columns from elements in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If `data.len() != rows * cols`.
+    #[inline]
+    #[track_caller]
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            rows.checked_mul(cols),
+            Some(data.len()),
+            "a matrix with {rows} rows and {cols} columns can't have {} elements",
+            data.len(),
+        );
+        Self { data, rows, cols }
+    }
+
+    /// Create a matrix with `rows` rows and `cols` columns, whose element at `(i, j)` is `f(i, j)`.
+    #[inline]
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { data, rows, cols }
+    }
+
+    /// Get all elements in row-major order, and drop the shape.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Number of rows.
+    #[inline]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns.
+    #[inline]
+    pub const fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Check whether there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get all elements in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Get all elements in row-major order mutably.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Get the element at row `i` and column `j`, or [None] if out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i < self.rows && j < self.cols {
+            self.data.get(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// Get the element at row `i` and column `j` mutably, or [None] if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.rows && j < self.cols {
+            self.data.get_mut(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over rows as slices.
+    #[inline]
+    pub fn iter(&self) -> Rows<'_, T> {
+        Rows {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Iterate over rows as mutable slices.
+    #[inline]
+    pub fn iter_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut {
+            data: &mut self.data,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Iterate over elements in column `j`.
+    ///
+    /// # Panics
+    ///
+    /// If `j` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn col(&self, j: usize) -> Col<'_, T> {
+        assert!(
+            j < self.cols,
+            "column index {j} is out of bounds for a matrix with {} columns",
+            self.cols,
+        );
+        // `self.data` is empty if there are no rows, even if `j` is a valid column.
+        let data = self.data.get(j..).unwrap_or(&[]);
+        Col {
+            iter: data.iter().step_by(self.cols),
+        }
+    }
+
+    /// Create a new matrix whose rows are the columns of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+    }
+}
+
+impl<T> Default for Mat<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Mat<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for Mat<T> {
+    type Output = [T];
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, i: usize) -> &[T] {
+        assert!(
+            i < self.rows,
+            "row index {i} is out of bounds for a matrix with {} rows",
+            self.rows,
+        );
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+impl<T> IndexMut<usize> for Mat<T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, i: usize) -> &mut [T] {
+        assert!(
+            i < self.rows,
+            "row index {i} is out of bounds for a matrix with {} rows",
+            self.rows,
+        );
+        &mut self.data[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Mat<T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(
+            j < self.cols,
+            "column index {j} is out of bounds for a matrix with {} columns",
+            self.cols,
+        );
+        &self[i][j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat<T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            j < self.cols,
+            "column index {j} is out of bounds for a matrix with {} columns",
+            self.cols,
+        );
+        &mut self[i][j]
+    }
+}
+
+/// Convert rows into a matrix.
+///
+/// # Panics
+///
+/// If rows have different lengths.
+impl<T> From<Vec<Vec<T>>> for Mat<T> {
+    #[inline]
+    #[track_caller]
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        rows.into_iter().collect()
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for Mat<T> {
+    #[inline]
+    fn from(rows: [[T; N]; M]) -> Self {
+        let data = rows.into_iter().flatten().collect();
+        Self {
+            data,
+            rows: M,
+            cols: N,
+        }
+    }
+}
+
+impl<T> From<Mat<T>> for Vec<Vec<T>> {
+    #[inline]
+    fn from(mat: Mat<T>) -> Self {
+        let Mat { data, rows, cols } = mat;
+        let mut iter = data.into_iter();
+        (0..rows)
+            .map(|_| iter.by_ref().take(cols).collect())
+            .collect()
+    }
+}
+
+/// Collect rows into a matrix.
+///
+/// # Panics
+///
+/// If rows have different lengths.
+impl<T> FromIterator<Vec<T>> for Mat<T> {
+    #[inline]
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let mut mat = Self::new();
+        for row in iter {
+            if mat.rows == 0 {
+                mat.cols = row.len();
+                mat.data.reserve(row.len());
+            }
+            assert_eq!(
+                row.len(),
+                mat.cols,
+                "row {} has {} elements, but previous rows have {}",
+                mat.rows,
+                row.len(),
+                mat.cols,
+            );
+            mat.data.extend(row);
+            mat.rows += 1;
+        }
+        mat
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Mat<T> {
+    type Item = &'a [T];
+    type IntoIter = Rows<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Rows<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Mat<T> {
+    type Item = &'a mut [T];
+    type IntoIter = RowsMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> RowsMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<Vec<U>>> for Mat<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<Vec<U>>) -> bool {
+        self.rows == other.len() && self.iter().zip(other).all(|(a, b)| a == b.as_slice())
+    }
+}
+
+impl<T: PartialEq<U>, U, const M: usize, const N: usize> PartialEq<[[U; N]; M]> for Mat<T> {
+    #[inline]
+    fn eq(&self, other: &[[U; N]; M]) -> bool {
+        self.rows == M && self.iter().zip(other).all(|(a, b)| a == b.as_slice())
+    }
+}
+
+/// Iterator over rows of a [Mat].
+#[derive(Debug)]
+pub struct Rows<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Clone for Rows<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.rows == 0 {
+            return None;
+        }
+        let (row, rest) = self.data.split_at(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.rows == 0 {
+            return None;
+        }
+        let (rest, row) = self.data.split_at(self.data.len() - self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+impl<T> FusedIterator for Rows<'_, T> {}
+
+/// Mutable iterator over rows of a [Mat].
+#[derive(Debug)]
+pub struct RowsMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.rows == 0 {
+            return None;
+        }
+        let (row, rest) = take(&mut self.data).split_at_mut(self.cols);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.rows == 0 {
+            return None;
+        }
+        let data = take(&mut self.data);
+        let mid = data.len() - self.cols;
+        let (rest, row) = data.split_at_mut(mid);
+        self.data = rest;
+        self.rows -= 1;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for RowsMut<'_, T> {}
+
+impl<T> FusedIterator for RowsMut<'_, T> {}
+
+/// Iterator over a column of a [Mat].
+#[derive(Debug, Clone)]
+pub struct Col<'a, T> {
+    iter: std::iter::StepBy<std::slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Col<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Col<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Col<'_, T> {}
+
+impl<T> FusedIterator for Col<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Mat;
+
+    #[test]
+    fn zero_columns() {
+        let mat: Mat<u8> = Mat::from_vec(3, 0, Vec::new());
+        assert_eq!(mat.iter().len(), 3);
+        assert!(mat.iter().all(|row| row.is_empty()));
+        assert!(mat.iter().rev().all(|row| row.is_empty()));
+        assert!(mat.is_empty());
+        assert_eq!(mat.transpose().rows(), 0);
+        assert_eq!(Vec::from(mat), vec![Vec::<u8>::new(); 3]);
+    }
+
+    #[test]
+    fn rows() {
+        let mut mat = Mat::from_fn(3, 2, |i, j| i * 10 + j);
+        assert_eq!(mat, [[0, 1], [10, 11], [20, 21]]);
+        assert_eq!(mat.iter().next_back(), Some([20, 21].as_slice()));
+        for row in mat.iter_mut().rev() {
+            row.reverse();
+        }
+        assert_eq!(mat, [[1, 0], [11, 10], [21, 20]]);
+        assert_eq!(mat.get(2, 1), Some(&20));
+        assert_eq!(mat.get(3, 0), None);
+        assert_eq!(mat.get(0, 2), None);
+        assert_eq!(mat.col(0).rev().copied().collect::<Vec<_>>(), [21, 11, 1]);
+        assert_eq!(mat.col(1).len(), 3);
+    }
+
+    #[test]
+    #[should_panic = "column index 2 is out of bounds for a matrix with 2 columns"]
+    fn column_out_of_bounds() {
+        let mat = Mat::from([[1, 2], [3, 4]]);
+        let _ = mat[(0, 2)];
+    }
+
+    #[test]
+    #[should_panic = "row 1 has 1 elements, but previous rows have 2"]
+    fn ragged() {
+        let _ = Mat::from(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    #[should_panic = "a matrix with 2 rows and 2 columns can't have 3 elements"]
+    fn wrong_len() {
+        let _ = Mat::from_vec(2, 2, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,208 @@
+//! Integers written in other radixes than 10.
+//!
+//! - [Hex] reads and writes hexadecimal integers, such as `deadbeef` or `0xDEADBEEF`;
+//! - [Oct] reads and writes octal integers, such as `755` or `0o755`;
+//! - [Bin] reads and writes binary integers, such as `1011` or `0b1011`;
+//! - [AutoRadix] detects the radix from the prefix `0x`, `0o` or `0b`, and falls back to decimal.
+//!
+//! All of them accept an optional sign before the prefix, and are written in sign-magnitude form
+//! without any prefix (or in decimal for [AutoRadix]), so that what is written can be read back.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iof::{radix::{AutoRadix, Bin, Hex}, InputStream, ReadInto};
+//!
+//! let mut reader = InputStream::new("deadbeef 0b1011 -0x1f 17".as_bytes());
+//! let a: Hex<u32> = reader.read();
+//! let b: Bin<u8> = reader.read();
+//! let c: AutoRadix<i32> = reader.read();
+//! let d: AutoRadix<i32> = reader.read();
+//! assert_eq!(a, Hex(0xdeadbeef));
+//! assert_eq!(b, Bin(0b1011));
+//! assert_eq!(c, AutoRadix(-0x1f));
+//! assert_eq!(d, AutoRadix(17));
+//! assert_eq!(format!("{a} {b} {c}"), "deadbeef 1011 -31");
+//! ```
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+/// A hexadecimal integer.
+///
+/// An optional `0x` or `0X` prefix is accepted when reading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex<T>(pub T);
+
+/// An octal integer.
+///
+/// An optional `0o` or `0O` prefix is accepted when reading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Oct<T>(pub T);
+
+/// A binary integer.
+///
+/// An optional `0b` or `0B` prefix is accepted when reading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bin<T>(pub T);
+
+/// An integer whose radix is detected from its prefix.
+///
+/// `0x` means hexadecimal, `0o` means octal, `0b` means binary, and no prefix means decimal.
+/// It is written in decimal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AutoRadix<T>(pub T);
+
+/// Get the radix from the prefix letter after `0`.
+#[inline]
+pub(crate) const fn radix_of_prefix(c: char) -> Option<u32> {
+    match c {
+        'x' | 'X' => Some(16),
+        'o' | 'O' => Some(8),
+        'b' | 'B' => Some(2),
+        _ => None,
+    }
+}
+
+/// Split `s` into its sign and the rest.
+#[inline]
+fn split_sign(s: &str) -> (&str, &str) {
+    match s.as_bytes().first() {
+        Some(b'+' | b'-') => s.split_at(1),
+        _ => s.split_at(0),
+    }
+}
+
+/// Split the prefix `0` followed by a letter from `s`, and return the radix it stands for.
+#[inline]
+fn split_prefix(s: &str) -> Option<(u32, &str)> {
+    let rest = s.strip_prefix('0')?;
+    let mut chars = rest.chars();
+    let radix = radix_of_prefix(chars.next()?)?;
+    Some((radix, chars.as_str()))
+}
+
+/// Parse `s` in `radix`.
+///
+/// If `expected` is [None], the radix is detected from the prefix, otherwise only the prefix of
+/// `expected` is accepted.
+#[inline]
+fn from_str_radix<T>(
+    s: &str,
+    expected: Option<u32>,
+    parse: fn(&str, u32) -> Result<T, ParseIntError>,
+) -> Result<T, ParseIntError> {
+    let (sign, rest) = split_sign(s);
+    let (radix, digits) = match split_prefix(rest) {
+        Some((radix, digits)) if expected.is_none_or(|expected| expected == radix) => {
+            (radix, digits)
+        }
+        _ => return parse(s, expected.unwrap_or(10)),
+    };
+    match (sign, digits.as_bytes().first()) {
+        // The prefix letter is not a digit in `radix`, so this fails.
+        (_, Some(b'+' | b'-')) => parse(s, radix),
+        ("-", _) => parse(&format!("-{digits}"), radix),
+        _ => parse(digits, radix),
+    }
+}
+
+macro_rules! impl_radix {
+    (@ $wrapper:ident $radix:expr, $fmt:ident: $($ty:ty => $abs:ident)*) => {
+        $(
+            impl FromStr for $wrapper<$ty> {
+                type Err = ParseIntError;
+
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    from_str_radix(s, $radix, <$ty>::from_str_radix).map(Self)
+                }
+            }
+
+            impl fmt::Display for $wrapper<$ty> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if impl_radix!(@negative $abs self.0) {
+                        f.write_str("-")?;
+                    }
+                    fmt::$fmt::fmt(&impl_radix!(@abs $abs self.0), f)
+                }
+            }
+        )*
+    };
+    (@negative unsigned_abs $value:expr) => {
+        $value < 0
+    };
+    (@negative identity $value:expr) => {
+        false
+    };
+    (@abs unsigned_abs $value:expr) => {
+        $value.unsigned_abs()
+    };
+    (@abs identity $value:expr) => {
+        $value
+    };
+    ($($ty:ty => $abs:ident)*) => {
+        impl_radix!(@ Hex Some(16), LowerHex: $($ty => $abs)*);
+        impl_radix!(@ Oct Some(8), Octal: $($ty => $abs)*);
+        impl_radix!(@ Bin Some(2), Binary: $($ty => $abs)*);
+        impl_radix!(@ AutoRadix None, Display: $($ty => $abs)*);
+    };
+}
+
+impl_radix!(
+    i8 => unsigned_abs
+    i16 => unsigned_abs
+    i32 => unsigned_abs
+    i64 => unsigned_abs
+    i128 => unsigned_abs
+    isize => unsigned_abs
+
+    u8 => identity
+    u16 => identity
+    u32 => identity
+    u64 => identity
+    u128 => identity
+    usize => identity
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoRadix, Bin, Hex, Oct};
+
+    #[test]
+    fn parse() {
+        assert_eq!("ff".parse(), Ok(Hex(255u8)));
+        assert_eq!("0XFF".parse(), Ok(Hex(255u8)));
+        assert_eq!("-0x80".parse(), Ok(Hex(i8::MIN)));
+        assert_eq!("+0o17".parse(), Ok(Oct(15u32)));
+        assert_eq!("0b1".parse(), Ok(Hex(0xb1u32)));
+        assert_eq!("0b101".parse(), Ok(Bin(5i64)));
+        assert_eq!("0o17".parse(), Ok(AutoRadix(15u16)));
+        assert_eq!("-0b11".parse(), Ok(AutoRadix(-3i16)));
+        assert_eq!("017".parse(), Ok(AutoRadix(17usize)));
+        assert_eq!("0".parse(), Ok(AutoRadix(0isize)));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert!("0x".parse::<Hex<u8>>().is_err());
+        assert!("0x-1".parse::<Hex<i8>>().is_err());
+        assert!("-0x1".parse::<Hex<u8>>().is_err());
+        assert!("0x81".parse::<Hex<i8>>().is_err());
+        assert!("-0x81".parse::<Hex<i8>>().is_err());
+        assert!("0o8".parse::<Oct<u8>>().is_err());
+        assert!("0x1".parse::<Oct<u8>>().is_err());
+        assert!("2".parse::<Bin<u8>>().is_err());
+        assert!("0z1".parse::<AutoRadix<u8>>().is_err());
+        assert!("".parse::<AutoRadix<u8>>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Hex(-255i32).to_string(), "-ff");
+        assert_eq!(Hex(i8::MIN).to_string(), "-80");
+        assert_eq!(Oct(8u8).to_string(), "10");
+        assert_eq!(Bin(-5i8).to_string(), "-101");
+        assert_eq!(AutoRadix(-5i8).to_string(), "-5");
+        assert_eq!(Hex(u128::MAX).to_string(), "f".repeat(32));
+    }
+}
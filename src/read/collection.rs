@@ -0,0 +1,311 @@
+use super::{fmt::Format, read_from::ReadFromError};
+use crate::{
+    stream::{line_buf::LineBuf, traits::BufReadExtWithFormat},
+    BufReadExt, ReadError, ReadFrom,
+};
+use std::{
+    any::type_name,
+    collections::{
+        btree_map, hash_map, BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque,
+    },
+    hash::Hash,
+};
+
+/// Policy for duplicate keys when reading a set or a map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Duplicate {
+    /// Return [ReadError::DuplicateKey].
+    Error,
+    /// Keep the first element, and discard later ones.
+    KeepFirst,
+    /// Keep the last element, which is what [FromIterator] does for maps.
+    #[default]
+    KeepLast,
+}
+
+/// Collections that can be read element by element.
+///
+/// Besides the line form provided by [ReadFrom], which reads all elements in a non-empty line,
+/// elements can also be read from a given count, and duplicate keys can be handled with a
+/// [Duplicate] policy.
+///
+/// The count form is [ReadCollection::try_read_collection_n_from], or `read!(collect n)`,
+/// rather than [ReadFrom::try_read_n_from], because the latter reads `n` whole collections
+/// into a [Vec], as it does for any other type.
+///
+/// Maps read their elements as `k v` pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{fmt::Default, Duplicate, InputStream, ReadCollection};
+/// use std::collections::{BTreeMap, HashSet};
+///
+/// let mut reader = InputStream::new("1 a\n2 b\n1 c\n3 1 4 1 5".as_bytes());
+///
+/// let map = BTreeMap::<u32, char>::try_read_collection_n_from(
+///     &mut reader,
+///     3,
+///     Default::new(),
+///     Duplicate::KeepFirst,
+/// )
+/// .unwrap();
+/// assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'b')]));
+///
+/// let set = HashSet::<u32>::try_read_collection_in_line_from(
+///     &mut reader,
+///     Default::new(),
+///     Duplicate::Error,
+/// );
+/// assert!(set.is_err());
+/// ```
+pub trait ReadCollection: Sized {
+    /// The element type.
+    type Item: ReadFrom;
+
+    /// Build a collection from `items`, handling duplicate keys with `duplicate`.
+    fn try_from_items<I>(items: I, duplicate: Duplicate) -> Result<Self, ReadFromError<Self::Item>>
+    where
+        I: IntoIterator<Item = Result<Self::Item, ReadFromError<Self::Item>>>;
+
+    /// Read `n` elements from `stream` into a collection.
+    #[inline]
+    fn try_read_collection_n_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+        duplicate: Duplicate,
+    ) -> Result<Self, ReadFromError<Self::Item>> {
+        let items = (0..n).map(|_| Self::Item::try_read_from(stream, format));
+        Self::try_from_items(items, duplicate)
+    }
+
+    /// Read all elements in a non-empty line from `stream` into a collection.
+    #[inline]
+    fn try_read_collection_in_line_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+        duplicate: Duplicate,
+    ) -> Result<Self, ReadFromError<Self::Item>> {
+        let mut line = LineBuf::new(stream.try_get_line_some_trimmed(format.skip())?);
+        let items = std::iter::from_fn(|| {
+            match line.try_skip_all(format.skip()) {
+                Ok(_) if line.get_cur_line().is_empty() => return None,
+                Ok(_) => {}
+                Err(err) => return Some(Err(err.into())),
+            }
+            Some(Self::Item::try_read_from(&mut line, format))
+        });
+        Self::try_from_items(items, duplicate)
+    }
+}
+
+/// Create the error for a duplicate key in the element at `index`, which is counted from 0.
+#[cold]
+fn duplicate_key<C, E>(index: usize) -> ReadError<E> {
+    ReadError::DuplicateKey {
+        element: index + 1,
+        name: type_name::<C>(),
+    }
+}
+
+impl<T: ReadFrom> ReadCollection for Vec<T> {
+    type Item = T;
+
+    #[inline]
+    fn try_from_items<I>(items: I, _duplicate: Duplicate) -> Result<Self, ReadFromError<T>>
+    where
+        I: IntoIterator<Item = Result<T, ReadFromError<T>>>,
+    {
+        items.into_iter().collect()
+    }
+}
+
+/// Read a [String] character by character.
+impl ReadCollection for String {
+    type Item = char;
+
+    #[inline]
+    fn try_from_items<I>(items: I, _duplicate: Duplicate) -> Result<Self, ReadFromError<char>>
+    where
+        I: IntoIterator<Item = Result<char, ReadFromError<char>>>,
+    {
+        items.into_iter().collect()
+    }
+}
+
+impl<T: ReadFrom> ReadCollection for VecDeque<T> {
+    type Item = T;
+
+    #[inline]
+    fn try_from_items<I>(items: I, _duplicate: Duplicate) -> Result<Self, ReadFromError<T>>
+    where
+        I: IntoIterator<Item = Result<T, ReadFromError<T>>>,
+    {
+        items.into_iter().collect()
+    }
+}
+
+impl<T: ReadFrom + Ord> ReadCollection for BinaryHeap<T> {
+    type Item = T;
+
+    #[inline]
+    fn try_from_items<I>(items: I, _duplicate: Duplicate) -> Result<Self, ReadFromError<T>>
+    where
+        I: IntoIterator<Item = Result<T, ReadFromError<T>>>,
+    {
+        items.into_iter().collect()
+    }
+}
+
+impl<T: ReadFrom + Eq + Hash> ReadCollection for HashSet<T> {
+    type Item = T;
+
+    fn try_from_items<I>(items: I, duplicate: Duplicate) -> Result<Self, ReadFromError<T>>
+    where
+        I: IntoIterator<Item = Result<T, ReadFromError<T>>>,
+    {
+        let mut set = Self::new();
+        for (index, item) in items.into_iter().enumerate() {
+            let item = item?;
+            match duplicate {
+                Duplicate::Error if set.contains(&item) => {
+                    return Err(duplicate_key::<Self, _>(index))
+                }
+                Duplicate::Error | Duplicate::KeepFirst => {
+                    let _: bool = set.insert(item);
+                }
+                Duplicate::KeepLast => {
+                    let _: Option<T> = set.replace(item);
+                }
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl<T: ReadFrom + Ord> ReadCollection for BTreeSet<T> {
+    type Item = T;
+
+    fn try_from_items<I>(items: I, duplicate: Duplicate) -> Result<Self, ReadFromError<T>>
+    where
+        I: IntoIterator<Item = Result<T, ReadFromError<T>>>,
+    {
+        let mut set = Self::new();
+        for (index, item) in items.into_iter().enumerate() {
+            let item = item?;
+            match duplicate {
+                Duplicate::Error if set.contains(&item) => {
+                    return Err(duplicate_key::<Self, _>(index))
+                }
+                Duplicate::Error | Duplicate::KeepFirst => {
+                    let _: bool = set.insert(item);
+                }
+                Duplicate::KeepLast => {
+                    let _: Option<T> = set.replace(item);
+                }
+            }
+        }
+        Ok(set)
+    }
+}
+
+macro_rules! impl_read_collection_for_map {
+    ($($map:ident $entry:ident [$($bound:tt)*])*) => {
+        $(
+            impl<K, V> ReadCollection for $map<K, V>
+            where
+                K: ReadFrom + $($bound)*,
+                V: ReadFrom,
+            {
+                type Item = (K, V);
+
+                fn try_from_items<I>(
+                    items: I,
+                    duplicate: Duplicate,
+                ) -> Result<Self, ReadFromError<(K, V)>>
+                where
+                    I: IntoIterator<Item = Result<(K, V), ReadFromError<(K, V)>>>,
+                {
+                    let mut map = Self::new();
+                    for (index, item) in items.into_iter().enumerate() {
+                        let (key, value) = item?;
+                        match (map.entry(key), duplicate) {
+                            ($entry::Entry::Vacant(entry), _) => {
+                                let _: &mut V = entry.insert(value);
+                            }
+                            ($entry::Entry::Occupied(_), Duplicate::Error) => {
+                                return Err(duplicate_key::<Self, _>(index))
+                            }
+                            ($entry::Entry::Occupied(_), Duplicate::KeepFirst) => {}
+                            ($entry::Entry::Occupied(mut entry), Duplicate::KeepLast) => {
+                                let _: V = entry.insert(value);
+                            }
+                        }
+                    }
+                    Ok(map)
+                }
+            }
+
+            /// Read `k v` pairs in a line from input stream, and keep the last value for duplicate keys.
+            ///
+            /// Such as:
+            ///
+            /// ```txt
+            /// 1 a 2 b 3 c
+            /// ```
+            impl<K, V> ReadFrom for $map<K, V>
+            where
+                K: ReadFrom + $($bound)*,
+                V: ReadFrom,
+            {
+                type ParseError = <(K, V) as ReadFrom>::ParseError;
+
+                #[inline]
+                fn try_read_from<F: Format, S: BufReadExt>(
+                    stream: &mut S,
+                    format: F,
+                ) -> Result<Self, ReadFromError<Self>> {
+                    Self::try_read_collection_in_line_from(stream, format, Duplicate::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_read_collection_for_map!(
+    HashMap hash_map [Eq + Hash]
+    BTreeMap btree_map [Ord]
+);
+
+macro_rules! impl_read_from_for_collection {
+    ($($collection:ident [$($bound:tt)*])*) => {
+        $(
+            /// Read several data items in a line from input stream.
+            ///
+            /// Such as:
+            ///
+            /// ```txt
+            /// 1 2 3
+            /// ```
+            impl<T: ReadFrom + $($bound)*> ReadFrom for $collection<T> {
+                type ParseError = <T as ReadFrom>::ParseError;
+
+                #[inline]
+                fn try_read_from<F: Format, S: BufReadExt>(
+                    stream: &mut S,
+                    format: F,
+                ) -> Result<Self, ReadFromError<Self>> {
+                    Self::try_read_collection_in_line_from(stream, format, Duplicate::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_read_from_for_collection!(
+    VecDeque []
+    BinaryHeap [Ord]
+    HashSet [Eq + Hash]
+    BTreeSet [Ord]
+);
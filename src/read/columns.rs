@@ -0,0 +1,46 @@
+use super::{fmt::Format, read_from::ReadFromError};
+use crate::{BufReadExt, ReadFrom};
+
+/// Tuples that can be read as columns, i.e., a tuple of [Vec]s.
+///
+/// This is implemented for tuples of 1 to 12 elements that implement [ReadFrom].
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{fmt::Default, InputStream, ReadColumns};
+///
+/// let mut reader = InputStream::new("1 a\n2 b\n3 c".as_bytes());
+/// let (a, b) = <(u32, char)>::try_read_columns_from(&mut reader, 3, Default::new()).unwrap();
+/// assert_eq!(a, [1, 2, 3]);
+/// assert_eq!(b, ['a', 'b', 'c']);
+/// ```
+pub trait ReadColumns: ReadFrom {
+    /// The columns, such as `(Vec<A>, Vec<B>)` for `(A, B)`.
+    type Columns: Columns<Row = Self>;
+
+    /// Read `n` rows from `stream`, and push each element into its own column directly.
+    fn try_read_columns_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+    ) -> Result<Self::Columns, ReadFromError<Self>>;
+}
+
+/// The opposite of [ReadColumns], which infers the row type from the columns.
+pub trait Columns: Sized {
+    /// The row type, such as `(A, B)` for `(Vec<A>, Vec<B>)`.
+    type Row: ReadColumns<Columns = Self>;
+
+    /// Read `n` rows from `stream` into columns.
+    ///
+    /// See [ReadColumns::try_read_columns_from].
+    #[inline]
+    fn try_read_columns_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+    ) -> Result<Self, ReadFromError<Self::Row>> {
+        Self::Row::try_read_columns_from(stream, n, format)
+    }
+}
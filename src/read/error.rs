@@ -1,5 +1,8 @@
 use crate::stream::{error::StreamError, MSG_EOF, MSG_EOL};
-use std::fmt::{self, Debug, Display};
+use std::{
+    fmt::{self, Debug, Display},
+    ops::Range,
+};
 
 /// Error during using [ReadInto] or [ReadOneFrom].
 ///
@@ -7,7 +10,10 @@ use std::fmt::{self, Debug, Display};
 ///
 /// [ReadInto]: crate::ReadInto
 /// [ReadOneFrom]: crate::ReadOneFrom
+///
+/// More variants may be added in the future, so it's marked as non-exhaustive.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ReadError<E> {
     /// Error during reading from input.
     IOError(std::io::Error),
@@ -21,6 +27,82 @@ pub enum ReadError<E> {
     // Unfulfilled(String),
     /// Error during converting a string to a value, usually caused by calling [std::str::FromStr::from_str].
     FromStrError(E, String, &'static str),
+    /// Duplicate key during reading a collection.
+    DuplicateKey {
+        /// Position of the element with the duplicate key among all elements, counted from 1.
+        element: usize,
+        /// Name of the collection type.
+        name: &'static str,
+    },
+    /// A row has a different number of cells from previous rows, during reading a grid or a matrix.
+    RaggedRow {
+        /// Line number of the row, counted from 1.
+        line: usize,
+        /// Number of cells in previous rows.
+        expected: usize,
+        /// Number of cells in this row.
+        found: usize,
+    },
+    /// A line has a different number of tokens from the expected one.
+    TokenCount {
+        /// Line number, counted from 1.
+        line: usize,
+        /// Expected number of tokens.
+        expected: usize,
+        /// Number of tokens in the line.
+        found: usize,
+    },
+    /// A vertex id is out of range, during reading a graph.
+    VertexOutOfRange {
+        /// Line number of the edge, counted from 1.
+        line: usize,
+        /// Range of valid vertex ids.
+        expected: Range<usize>,
+        /// The vertex id in input.
+        found: usize,
+    },
+}
+
+impl<E> ReadError<E> {
+    /// Convert the parse error with `f`, and keep other variants as they are.
+    #[inline]
+    pub(crate) fn map_parse_error<F>(self, f: impl FnOnce(E) -> F) -> ReadError<F> {
+        match self {
+            Self::IOError(e) => ReadError::IOError(e),
+            Self::EOF => ReadError::EOF,
+            Self::EOL => ReadError::EOL,
+            Self::UnexpectedChar(c, s) => ReadError::UnexpectedChar(c, s),
+            Self::FromStrError(e, s, n) => ReadError::FromStrError(f(e), s, n),
+            Self::DuplicateKey { element, name } => ReadError::DuplicateKey { element, name },
+            Self::RaggedRow {
+                line,
+                expected,
+                found,
+            } => ReadError::RaggedRow {
+                line,
+                expected,
+                found,
+            },
+            Self::TokenCount {
+                line,
+                expected,
+                found,
+            } => ReadError::TokenCount {
+                line,
+                expected,
+                found,
+            },
+            Self::VertexOutOfRange {
+                line,
+                expected,
+                found,
+            } => ReadError::VertexOutOfRange {
+                line,
+                expected,
+                found,
+            },
+        }
+    }
 }
 
 impl<E> Display for ReadError<E>
@@ -40,6 +122,10 @@ where
                 Display::fmt(error, f)
             }
             Self::UnexpectedChar(s, t) => write!(f, "found unexpected character at the end of the string {s:?} during converting it to a value of {t:?}"),
+            Self::DuplicateKey { element, name } => write!(f, "found duplicate key in element {element} during reading a value of `{name}`"),
+            Self::RaggedRow { line, expected, found } => write!(f, "expected {expected} cells on line {line}, found {found}"),
+            Self::TokenCount { line, expected, found } => write!(f, "expected {expected} tokens on line {line}, found {found}"),
+            Self::VertexOutOfRange { line, expected, found } => write!(f, "expected a vertex in {expected:?} on line {line}, found {found}"),
             // Self::Unfulfilled(s) => write!(f, "unfulfilled pattern in {s:?}"),
         }
     }
@@ -0,0 +1,164 @@
+use super::read_from::ReadFromError;
+use crate::{BufReadExt, ReadError, ReadFrom, ReadOneFrom};
+use std::{any::type_name, ops::Range};
+
+/// Unit of positions in a [FixedWidth] layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WidthUnit {
+    /// Positions are byte offsets.
+    #[default]
+    Byte,
+    /// Positions are character offsets.
+    Char,
+}
+
+/// Alignment of a field in a [FixedWidth] layout, used when writing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Pad spaces after the value.
+    Left,
+    /// Pad spaces before the value.
+    #[default]
+    Right,
+}
+
+/// Layout of fixed-width columns without delimiters, such as:
+///
+/// ```txt
+/// 000001   12.50Alice
+/// 000002  100.00Bob
+/// ```
+///
+/// where characters 1 to 6 are an id, 7 to 14 are an amount, and the rest is a name.
+///
+/// Each field is a half-open range of positions counted from 0, so the layout above is
+/// `[0..6, 6..14, 14..24]`. Spaces around a field are trimmed before parsing,
+/// and fields beyond the end of a line are empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{Align, FixedWidth, InputStream, ReadInto};
+///
+/// let layout = FixedWidth::chars([0..6, 6..14, 14..24]).aligned([Align::Right, Align::Right, Align::Left]);
+/// let mut reader = InputStream::new("000001   12.50Alice\n".as_bytes());
+/// let (id, amount, name): (u32, f64, String) = reader.read_fixed(&layout);
+/// assert_eq!((id, amount, name.as_str()), (1, 12.5, "Alice"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FixedWidth {
+    unit: WidthUnit,
+    fields: Vec<(Range<usize>, Align)>,
+}
+
+impl FixedWidth {
+    /// Create a layout whose fields are in `unit`, and are all right-aligned.
+    #[inline]
+    pub fn new(unit: WidthUnit, ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        let fields = ranges
+            .into_iter()
+            .map(|range| (range, Align::default()))
+            .collect();
+        Self { unit, fields }
+    }
+
+    /// Create a layout whose fields are byte ranges.
+    #[inline]
+    pub fn bytes(ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        Self::new(WidthUnit::Byte, ranges)
+    }
+
+    /// Create a layout whose fields are character ranges.
+    #[inline]
+    pub fn chars(ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        Self::new(WidthUnit::Char, ranges)
+    }
+
+    /// Set the alignment of fields in order.
+    ///
+    /// Fields without a given alignment keep their alignment.
+    #[inline]
+    pub fn aligned(mut self, aligns: impl IntoIterator<Item = Align>) -> Self {
+        for ((_, align), new) in self.fields.iter_mut().zip(aligns) {
+            *align = new;
+        }
+        self
+    }
+
+    /// Get the unit of positions.
+    #[inline]
+    pub const fn unit(&self) -> WidthUnit {
+        self.unit
+    }
+
+    /// Get the fields, each with its range and alignment.
+    #[inline]
+    pub fn fields(&self) -> &[(Range<usize>, Align)] {
+        &self.fields
+    }
+
+    /// Get the byte offset of position `pos` in `line`.
+    ///
+    /// Returns [None] if `pos` is not a character boundary.
+    fn offset(&self, line: &str, pos: usize) -> Option<usize> {
+        match self.unit {
+            WidthUnit::Byte if pos >= line.len() => Some(line.len()),
+            WidthUnit::Byte => line.is_char_boundary(pos).then_some(pos),
+            WidthUnit::Char => Some(line.char_indices().nth(pos).map_or(line.len(), |(i, _)| i)),
+        }
+    }
+
+    /// Split `line` into fields, with spaces around each field trimmed.
+    ///
+    /// Yields [None] for a field that does not start or end at a character boundary.
+    pub(crate) fn split<'s>(&'s self, line: &'s str) -> impl Iterator<Item = Option<&'s str>> {
+        self.fields.iter().map(move |(range, _)| {
+            let start = self.offset(line, range.start)?;
+            let end = self.offset(line, range.end)?.max(start);
+            Some(line[start..end].trim_matches(' '))
+        })
+    }
+}
+
+/// Get the next field for `T` from `fields`.
+#[inline]
+pub(crate) fn next_field<'s, T: ReadOneFrom>(
+    fields: &mut impl Iterator<Item = Option<&'s str>>,
+    line: &str,
+) -> Result<&'s str, ReadError<T::ParseError>> {
+    match fields.next() {
+        Some(Some(field)) => Ok(field),
+        Some(None) => Err(ReadError::UnexpectedChar(line.to_owned(), type_name::<T>())),
+        None => Err(ReadError::EOL),
+    }
+}
+
+/// Types that can be read from a line of fixed-width columns, with one field per element.
+///
+/// If there are fewer fields than elements, [ReadError::EOL] is returned,
+/// and extra fields are ignored.
+pub trait ReadFixed: ReadFrom {
+    /// Parse fields of `line` in `layout` into `Self`.
+    fn try_parse_fixed(line: &str, layout: &FixedWidth) -> Result<Self, ReadFromError<Self>>;
+
+    /// Read a non-empty line from `stream`, and parse its fields in `layout` into `Self`.
+    #[inline]
+    fn try_read_fixed_from<S: BufReadExt>(
+        stream: &mut S,
+        layout: &FixedWidth,
+    ) -> Result<Self, ReadFromError<Self>> {
+        let line = stream.try_get_line_some()?;
+        Self::try_parse_fixed(line, layout)
+    }
+}
+
+/// Read all fields as elements.
+impl<T: ReadOneFrom> ReadFixed for Vec<T> {
+    #[inline]
+    fn try_parse_fixed(line: &str, layout: &FixedWidth) -> Result<Self, ReadFromError<Self>> {
+        let mut fields = layout.split(line);
+        (0..layout.fields().len())
+            .map(|_| T::parse(next_field::<T>(&mut fields, line)?))
+            .collect()
+    }
+}
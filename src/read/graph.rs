@@ -0,0 +1,349 @@
+use super::{fmt, read_from::ReadFromError};
+use crate::{unwrap, BufReadExt, ReadError, ReadFrom};
+use std::ops::Index;
+
+/// Error during reading an unweighted edge with [ReadGraph].
+pub type EdgeError = ReadFromError<(usize, usize)>;
+
+/// Error during reading an edge with a weight of type `W` with [ReadGraph].
+pub type WeightedEdgeError<W> = ReadFromError<(usize, usize, W)>;
+
+/// Layout of vertices and edges in graph input, used by [ReadGraph].
+///
+/// A layout is either directed or undirected, and its vertex ids are counted from either 0 or 1.
+/// Vertices in the result are always counted from 0.
+///
+/// The default layout is undirected and 1-based, which is the most common one in problem inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GraphLayout {
+    directed: bool,
+    base: usize,
+}
+
+impl GraphLayout {
+    /// Undirected graphs with 1-based vertex ids.
+    #[inline]
+    pub const fn undirected() -> Self {
+        Self {
+            directed: false,
+            base: 1,
+        }
+    }
+
+    /// Directed graphs with 1-based vertex ids.
+    #[inline]
+    pub const fn directed() -> Self {
+        Self {
+            directed: true,
+            base: 1,
+        }
+    }
+
+    /// Count vertex ids from 0.
+    #[inline]
+    pub const fn zero_based(self) -> Self {
+        Self { base: 0, ..self }
+    }
+
+    /// Count vertex ids from 1.
+    #[inline]
+    pub const fn one_based(self) -> Self {
+        Self { base: 1, ..self }
+    }
+
+    /// Check whether edges are directed.
+    #[inline]
+    pub const fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Get the id of the first vertex.
+    #[inline]
+    pub const fn base(&self) -> usize {
+        self.base
+    }
+
+    /// Convert vertex id `v` on line `line` into an index in `0..n`.
+    fn index<E>(&self, v: usize, n: usize, line: usize) -> Result<usize, ReadError<E>> {
+        match v.checked_sub(self.base) {
+            Some(i) if i < n => Ok(i),
+            _ => Err(ReadError::VertexOutOfRange {
+                line,
+                expected: self.base..self.base + n,
+                found: v,
+            }),
+        }
+    }
+}
+
+impl Default for GraphLayout {
+    #[inline]
+    fn default() -> Self {
+        Self::undirected()
+    }
+}
+
+/// A compact graph in compressed sparse row (CSR) format.
+///
+/// All adjacency lists are stored in a single [Vec], so it's more cache-friendly than [`Vec<Vec<E>>`].
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::Csr;
+///
+/// let g = Csr::from_edges(3, [(0, 1), (2, 0), (0, 2)]);
+/// assert_eq!(g.len(), 3);
+/// assert_eq!(g[0], [1, 2]);
+/// assert_eq!(g[1], []);
+/// assert_eq!(g.neighbors(2), [0]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Csr<E> {
+    offsets: Vec<usize>,
+    targets: Vec<E>,
+}
+
+impl<E> Csr<E> {
+    /// Create a graph with `n` vertices from `(source, item)` pairs,
+    /// keeping the order of items with the same source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source is not in `0..n`.
+    #[inline]
+    pub fn from_edges(n: usize, edges: impl IntoIterator<Item = (usize, E)>) -> Self {
+        let mut edges: Vec<(usize, E)> = edges.into_iter().collect();
+        let mut offsets = vec![0; n + 1];
+        for &(source, _) in &edges {
+            assert!(source < n, "source {source} is out of range 0..{n}");
+            offsets[source + 1] += 1;
+        }
+        for i in 0..n {
+            offsets[i + 1] += offsets[i];
+        }
+        edges.sort_by_key(|&(source, _)| source);
+        let targets = edges.into_iter().map(|(_, item)| item).collect();
+        Self { offsets, targets }
+    }
+
+    /// Get the number of vertices.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Check whether there are no vertices.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the total number of items in all adjacency lists.
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Get the adjacency list of vertex `v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `v` is not in `0..self.len()`.
+    #[inline]
+    pub fn neighbors(&self, v: usize) -> &[E] {
+        &self.targets[self.offsets[v]..self.offsets[v + 1]]
+    }
+
+    /// Iterate over adjacency lists of all vertices.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &[E]> {
+        self.offsets.windows(2).map(|w| &self.targets[w[0]..w[1]])
+    }
+}
+
+impl<E> Index<usize> for Csr<E> {
+    type Output = [E];
+
+    #[inline]
+    fn index(&self, v: usize) -> &[E] {
+        self.neighbors(v)
+    }
+}
+
+/// Read `m` edges from `stream`, and collect them as `(source, item)` pairs in `0..n`.
+///
+/// Each edge is read as `T`, `split` splits it into its endpoints and weight,
+/// and `item` makes the item for the target and weight.
+fn try_read_edges<T: ReadFrom, W: Clone, E, S: BufReadExt>(
+    stream: &mut S,
+    n: usize,
+    m: usize,
+    layout: GraphLayout,
+    split: impl Fn(T) -> (usize, usize, W),
+    item: impl Fn(usize, W) -> E,
+) -> Result<Vec<(usize, E)>, ReadFromError<T>> {
+    let mut edges = Vec::with_capacity(if layout.directed { m } else { 2 * m });
+    for _ in 0..m {
+        let (u, v, w) = split(T::try_read_from(stream, fmt::Default::new())?);
+        let line = stream.line_number();
+        let u = layout.index(u, n, line)?;
+        let v = layout.index(v, n, line)?;
+        if !layout.directed {
+            edges.push((v, item(u, w.clone())));
+        }
+        edges.push((u, item(v, w)));
+    }
+    Ok(edges)
+}
+
+/// Collect `(source, item)` pairs into adjacency lists of `n` vertices.
+fn into_adjacency<E>(n: usize, edges: Vec<(usize, E)>) -> Vec<Vec<E>> {
+    let mut adj: Vec<Vec<E>> = (0..n).map(|_| Vec::new()).collect();
+    for (source, item) in edges {
+        adj[source].push(item);
+    }
+    adj
+}
+
+/// Read graphs from a stream, such as:
+///
+/// ```txt
+/// 1 2
+/// 2 3 5
+/// ```
+///
+/// Each edge is a pair of vertex ids, optionally followed by a weight of any [ReadFrom] type,
+/// and its vertices are in the given [GraphLayout]. In the result, vertices are counted from 0,
+/// an undirected edge appears in adjacency lists of both endpoints, and edges keep their order in input.
+///
+/// Vertex ids out of range are reported as [ReadError::VertexOutOfRange] with the line number.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{GraphLayout, InputStream, ReadGraph, ReadInto};
+///
+/// let mut reader = InputStream::new("3 2\n1 2\n2 3\n".as_bytes());
+/// let (n, m): (usize, usize) = reader.read();
+/// let g = reader.read_graph(n, m, GraphLayout::undirected());
+/// assert_eq!(g, [vec![1], vec![0, 2], vec![1]]);
+/// ```
+pub trait ReadGraph: BufReadExt + Sized {
+    /// Read `m` edges of a graph with `n` vertices into adjacency lists.
+    #[inline]
+    fn try_read_graph(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Result<Vec<Vec<usize>>, EdgeError> {
+        let edges = try_read_edges(self, n, m, layout, |(u, v)| (u, v, ()), |v, ()| v)?;
+        Ok(into_adjacency(n, edges))
+    }
+
+    /// Read `m` weighted edges of a graph with `n` vertices into adjacency lists of `(vertex, weight)` pairs.
+    #[inline]
+    fn try_read_weighted_graph<W: ReadFrom + Clone>(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Result<Vec<Vec<(usize, W)>>, WeightedEdgeError<W>> {
+        let edges = try_read_edges(self, n, m, layout, |(u, v, w)| (u, v, w), |v, w| (v, w))?;
+        Ok(into_adjacency(n, edges))
+    }
+
+    /// Read `m` edges of a graph with `n` vertices into a [Csr].
+    #[inline]
+    fn try_read_csr_graph(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Result<Csr<usize>, EdgeError> {
+        let edges = try_read_edges(self, n, m, layout, |(u, v)| (u, v, ()), |v, ()| v)?;
+        Ok(Csr::from_edges(n, edges))
+    }
+
+    /// Read `m` weighted edges of a graph with `n` vertices into a [Csr] of `(vertex, weight)` pairs.
+    #[inline]
+    fn try_read_weighted_csr_graph<W: ReadFrom + Clone>(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Result<Csr<(usize, W)>, WeightedEdgeError<W>> {
+        let edges = try_read_edges(self, n, m, layout, |(u, v, w)| (u, v, w), |v, w| (v, w))?;
+        Ok(Csr::from_edges(n, edges))
+    }
+
+    /// Read a tree with `n` vertices given as a parent array into adjacency lists.
+    ///
+    /// The parent array has `n - 1` elements, which are parents of vertices other than the first one, in order.
+    /// Edges are from parents to children if `layout` is directed.
+    #[inline]
+    fn try_read_tree_parents(
+        &mut self,
+        n: usize,
+        layout: GraphLayout,
+    ) -> Result<Vec<Vec<usize>>, ReadFromError<usize>> {
+        let mut adj = vec![Vec::new(); n];
+        for child in 1..n {
+            let p = usize::try_read_from(self, fmt::Default::new())?;
+            let p = layout.index(p, n, self.line_number())?;
+            adj[p].push(child);
+            if !layout.directed {
+                adj[child].push(p);
+            }
+        }
+        Ok(adj)
+    }
+
+    /// Unwrap the result of [ReadGraph::try_read_graph].
+    #[inline]
+    #[track_caller]
+    fn read_graph(&mut self, n: usize, m: usize, layout: GraphLayout) -> Vec<Vec<usize>> {
+        unwrap!(self.try_read_graph(n, m, layout))
+    }
+
+    /// Unwrap the result of [ReadGraph::try_read_weighted_graph].
+    #[inline]
+    #[track_caller]
+    fn read_weighted_graph<W: ReadFrom + Clone>(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Vec<Vec<(usize, W)>> {
+        unwrap!(self.try_read_weighted_graph(n, m, layout))
+    }
+
+    /// Unwrap the result of [ReadGraph::try_read_csr_graph].
+    #[inline]
+    #[track_caller]
+    fn read_csr_graph(&mut self, n: usize, m: usize, layout: GraphLayout) -> Csr<usize> {
+        unwrap!(self.try_read_csr_graph(n, m, layout))
+    }
+
+    /// Unwrap the result of [ReadGraph::try_read_weighted_csr_graph].
+    #[inline]
+    #[track_caller]
+    fn read_weighted_csr_graph<W: ReadFrom + Clone>(
+        &mut self,
+        n: usize,
+        m: usize,
+        layout: GraphLayout,
+    ) -> Csr<(usize, W)> {
+        unwrap!(self.try_read_weighted_csr_graph(n, m, layout))
+    }
+
+    /// Unwrap the result of [ReadGraph::try_read_tree_parents].
+    #[inline]
+    #[track_caller]
+    fn read_tree_parents(&mut self, n: usize, layout: GraphLayout) -> Vec<Vec<usize>> {
+        unwrap!(self.try_read_tree_parents(n, layout))
+    }
+}
+
+impl<S: BufReadExt> ReadGraph for S {}
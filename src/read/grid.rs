@@ -0,0 +1,161 @@
+use super::{fmt::Format, read_from::ReadFromError};
+use crate::{
+    ascii::char::Error, stream::traits::BufReadExtWithFormat, ASCIIChar, BufReadExt, Mat,
+    ReadError, ReadFrom,
+};
+use std::{any::type_name, io::Write};
+
+/// Cells of a character grid, such as:
+///
+/// ```txt
+/// #.#..
+/// ..#.#
+/// ```
+///
+/// Each row of the grid is a whole line, with leading and trailing whitespaces trimmed,
+/// and empty lines are skipped. Whitespaces inside a row are cells as well.
+///
+/// - [u8] reads each byte of a row as a cell.
+/// - [ASCIIChar] reads each byte of a row as a cell, and rejects non-ASCII bytes.
+/// - [char] reads each character of a row as a cell.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{fmt::Default, GridCell, InputStream};
+///
+/// let mut reader = InputStream::new("#.#\n..#\n".as_bytes());
+/// let grid = u8::try_read_grid_map_from(&mut reader, 2, Default::new(), |c: u8| c == b'#');
+/// assert_eq!(grid.unwrap(), [[true, false, true], [false, false, true]]);
+/// ```
+pub trait GridCell: ReadFrom {
+    /// Append the cells in `row` to `dest`.
+    fn try_extend_row(dest: &mut Vec<Self>, row: &str) -> Result<(), ReadFromError<Self>>;
+
+    /// Write `cells` into `s` without separators.
+    fn try_write_row<S: Write + ?Sized>(cells: &[Self], s: &mut S) -> std::io::Result<()>;
+
+    /// Read `n` rows from `stream` into a [Mat].
+    ///
+    /// Returns [ReadError::RaggedRow] if a row has a different width from the first row.
+    #[inline]
+    fn try_read_grid_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+    ) -> Result<Mat<Self>, ReadFromError<Self>> {
+        let mut data = Vec::new();
+        let mut cols = 0;
+        for i in 0..n {
+            let len = data.len();
+            let row = stream.try_get_line_some_trimmed(format.skip())?;
+            Self::try_extend_row(&mut data, row)?;
+            let width = data.len() - len;
+            if i == 0 {
+                cols = width;
+                data.reserve(cols * (n - 1));
+            } else if width != cols {
+                return Err(ReadError::RaggedRow {
+                    line: stream.line_number(),
+                    expected: cols,
+                    found: width,
+                });
+            }
+        }
+        Ok(Mat::from_vec(n, cols, data))
+    }
+
+    /// Read `n` rows from `stream`, which may have different widths.
+    #[inline]
+    fn try_read_grid_ragged_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+    ) -> Result<Vec<Vec<Self>>, ReadFromError<Self>> {
+        let mut rows = Vec::with_capacity(n);
+        for _ in 0..n {
+            let mut cells = Vec::new();
+            let row = stream.try_get_line_some_trimmed(format.skip())?;
+            Self::try_extend_row(&mut cells, row)?;
+            rows.push(cells);
+        }
+        Ok(rows)
+    }
+
+    /// Read `n` rows from `stream`, and map each cell with `f` into a [Mat].
+    ///
+    /// Returns [ReadError::RaggedRow] if a row has a different width from the first row.
+    #[inline]
+    fn try_read_grid_map_from<V, F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+        mut f: impl FnMut(Self) -> V,
+    ) -> Result<Mat<V>, ReadFromError<Self>> {
+        let mut data = Vec::new();
+        let mut cells = Vec::new();
+        let mut cols = 0;
+        for i in 0..n {
+            let row = stream.try_get_line_some_trimmed(format.skip())?;
+            Self::try_extend_row(&mut cells, row)?;
+            if i == 0 {
+                cols = cells.len();
+                data.reserve(cols * n);
+            } else if cells.len() != cols {
+                return Err(ReadError::RaggedRow {
+                    line: stream.line_number(),
+                    expected: cols,
+                    found: cells.len(),
+                });
+            }
+            data.extend(cells.drain(..).map(&mut f));
+        }
+        Ok(Mat::from_vec(n, cols, data))
+    }
+}
+
+impl GridCell for u8 {
+    #[inline]
+    fn try_extend_row(dest: &mut Vec<Self>, row: &str) -> Result<(), ReadFromError<Self>> {
+        dest.extend_from_slice(row.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn try_write_row<S: Write + ?Sized>(cells: &[Self], s: &mut S) -> std::io::Result<()> {
+        s.write_all(cells)
+    }
+}
+
+impl GridCell for ASCIIChar {
+    #[inline]
+    fn try_extend_row(dest: &mut Vec<Self>, row: &str) -> Result<(), ReadFromError<Self>> {
+        dest.reserve(row.len());
+        for &byte in row.as_bytes() {
+            let c = ASCIIChar::try_from(byte).map_err(|byte| {
+                ReadError::FromStrError(Error::Byte(byte), row.to_owned(), type_name::<Self>())
+            })?;
+            dest.push(c);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn try_write_row<S: Write + ?Sized>(cells: &[Self], s: &mut S) -> std::io::Result<()> {
+        s.write_all(ASCIIChar::slice_as_str(cells).as_bytes())
+    }
+}
+
+impl GridCell for char {
+    #[inline]
+    fn try_extend_row(dest: &mut Vec<Self>, row: &str) -> Result<(), ReadFromError<Self>> {
+        dest.extend(row.chars());
+        Ok(())
+    }
+
+    #[inline]
+    fn try_write_row<S: Write + ?Sized>(cells: &[Self], s: &mut S) -> std::io::Result<()> {
+        let row: String = cells.iter().collect();
+        s.write_all(row.as_bytes())
+    }
+}
@@ -1,4 +1,4 @@
-use super::fmt;
+use super::{fmt, read_one_from::get_string_some};
 use crate::{
     impl_read_one_from_for_from_str, ASCIIChar, ASCIIString, BufReadExt, ReadError, ReadOneFrom,
     ReadOneFromError,
@@ -6,15 +6,17 @@ use crate::{
 use std::{ffi::OsString, net::*, path::PathBuf};
 
 mod bool;
+mod decimal;
 mod float;
+mod index1;
+mod integer;
+mod radix;
 mod signed;
 mod unsigned;
 
 // Implement `Parse` for some built-in types.
 impl_read_one_from_for_from_str!(
-    /* char ASCIIChar */
-    String
-    ASCIIString
+    /* char ASCIIChar String ASCIIString */
     PathBuf
     OsString
 
@@ -22,6 +24,58 @@ impl_read_one_from_for_from_str!(
     SocketAddr SocketAddrV4 SocketAddrV6
 );
 
+impl ReadOneFrom for String {
+    type ParseError = <Self as ::std::str::FromStr>::Err;
+
+    #[inline]
+    fn parse(s: &str) -> Result<Self, ReadOneFromError<Self>> {
+        Ok(s.to_owned())
+    }
+
+    #[inline]
+    fn parse_into(&mut self, s: &str) -> Result<(), ReadOneFromError<Self>> {
+        self.clear();
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_read_one_in_place_from<F: fmt::Format, S: BufReadExt>(
+        &mut self,
+        stream: &mut S,
+        format: F,
+    ) -> Result<(), ReadOneFromError<Self>> {
+        self.parse_into(get_string_some::<Self, _, _>(stream, format)?)
+    }
+}
+
+impl ReadOneFrom for ASCIIString {
+    type ParseError = <Self as ::std::str::FromStr>::Err;
+
+    #[inline]
+    fn parse(s: &str) -> Result<Self, ReadOneFromError<Self>> {
+        s.parse().map_err(|err| {
+            ReadError::FromStrError(err, s.to_owned(), ::std::any::type_name::<Self>())
+        })
+    }
+
+    #[inline]
+    fn parse_into(&mut self, s: &str) -> Result<(), ReadOneFromError<Self>> {
+        self.assign(s).map_err(|err| {
+            ReadError::FromStrError(err, s.to_owned(), ::std::any::type_name::<Self>())
+        })
+    }
+
+    #[inline]
+    fn try_read_one_in_place_from<F: fmt::Format, S: BufReadExt>(
+        &mut self,
+        stream: &mut S,
+        format: F,
+    ) -> Result<(), ReadOneFromError<Self>> {
+        self.parse_into(get_string_some::<Self, _, _>(stream, format)?)
+    }
+}
+
 impl ReadOneFrom for char {
     type ParseError = <char as ::std::str::FromStr>::Err;
 
@@ -39,6 +93,16 @@ impl ReadOneFrom for char {
     ) -> Result<Self, ReadOneFromError<Self>> {
         <Self as ReadOneFrom>::try_read_in_char_from(stream, format)
     }
+
+    #[inline]
+    fn try_read_one_in_place_from<F: fmt::Format, S: BufReadExt>(
+        &mut self,
+        stream: &mut S,
+        format: F,
+    ) -> Result<(), ReadOneFromError<Self>> {
+        *self = <Self as ReadOneFrom>::try_read_in_char_from(stream, format)?;
+        Ok(())
+    }
 }
 
 impl ReadOneFrom for ASCIIChar {
@@ -58,4 +122,14 @@ impl ReadOneFrom for ASCIIChar {
     ) -> Result<Self, ReadOneFromError<Self>> {
         <Self as ReadOneFrom>::try_read_in_char_from(stream, format)
     }
+
+    #[inline]
+    fn try_read_one_in_place_from<F: fmt::Format, S: BufReadExt>(
+        &mut self,
+        stream: &mut S,
+        format: F,
+    ) -> Result<(), ReadOneFromError<Self>> {
+        *self = <Self as ReadOneFrom>::try_read_in_char_from(stream, format)?;
+        Ok(())
+    }
 }
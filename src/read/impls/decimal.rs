@@ -0,0 +1,35 @@
+use crate::{impl_read_one_from_for_from_str, Decimal};
+
+#[cfg(feature = "c-compatible")]
+mod inner {
+    use super::super::float::Float;
+    use crate::ext::{Pattern, State};
+
+    /// The sign, digits and dot part of the [Float] pattern, without exponent, infinity or NaN.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub(super) struct Decimal(Float);
+
+    impl Pattern for Decimal {
+        type Item = char;
+
+        #[inline]
+        fn step(&mut self, c: <Self as Pattern>::Item) -> bool {
+            match c {
+                '+' | '-' | '.' => self.0.step(c),
+                _ if c.is_ascii_digit() => self.0.step(c),
+                _ => false,
+            }
+        }
+
+        #[inline]
+        fn state(&self) -> State {
+            self.0.state()
+        }
+    }
+}
+
+#[cfg(feature = "c-compatible")]
+impl_read_one_from_for_from_str!(Decimal => inner::Decimal::default());
+
+#[cfg(not(feature = "c-compatible"))]
+impl_read_one_from_for_from_str!(Decimal);
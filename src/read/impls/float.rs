@@ -1,12 +1,29 @@
-use crate::impl_read_one_from_for_from_str;
+//! Direct float reader.
+//!
+//! Plain decimal numbers with at most [MAX_DIGITS] digits are scanned by a tight loop over bytes.
+//! Other tokens are scanned with the [Float] pattern, and the decimal number is accumulated in the same pass.
+//! It is then converted with [lemire::convert], which rounds correctly.
+//! [FromStr] is only called on the error path or when the conversion cannot be decided,
+//! to produce exactly the same result as [str::parse] does.
+
+pub(super) use self::inner::Float;
+use crate::{
+    ext::{Pattern, State},
+    fmt::Format,
+    stream::{ext::CharSet, traits::BufReadExtWithFormat},
+    BufReadExt, ReadError,
+};
+use lemire::RawFloat;
+use std::{any::type_name, num::ParseFloatError, str::FromStr};
+
+mod lemire;
 
-#[cfg(feature = "c-compatible")]
 mod inner {
     use crate::ext::{Pattern, State};
 
     /// See <https://doc.rust-lang.org/std/primitive.f64.html#impl-FromStr-for-f64>
-    #[derive(Debug, Clone, Copy, Default)]
-    pub(super) enum Float {
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub(in super::super) enum Float {
         #[default]
         Initial,
         /// `Sign`.
@@ -180,11 +197,459 @@ mod inner {
     }
 }
 
-// See <https://doc.rust-lang.org/std/primitive.f64.html#impl-FromStr-for-f64>
-#[cfg(feature = "c-compatible")]
-impl_read_one_from_for_from_str!(
-    f32 f64 => inner::Float::Initial
-);
+/// Maximum number of significant digits kept in the mantissa.
+const MAX_DIGITS: u32 = 19;
+
+/// Maximum absolute value of the explicit exponent that is tracked.
+///
+/// Any larger exponent rounds to zero or infinity anyway.
+const MAX_EXPONENT: i64 = 0x10000;
+
+/// Decimal number accumulated while scanning.
+#[derive(Debug, Clone, Copy, Default)]
+struct Accumulator {
+    negative: bool,
+    /// The first [MAX_DIGITS] significant digits.
+    mantissa: u64,
+    /// Count of significant digits in `mantissa`.
+    digits: u32,
+    /// Whether any non-zero digit is dropped from `mantissa`.
+    truncated: bool,
+    /// Whether any digit is seen before the exponent.
+    has_digits: bool,
+    /// Decimal exponent implied by the position of the dot and the dropped digits.
+    adjust: i64,
+    exponent_negative: bool,
+    /// Absolute value of the explicit exponent, saturated at [MAX_EXPONENT].
+    exponent: i64,
+}
+
+impl Accumulator {
+    #[inline]
+    fn push_digit(&mut self, digit: u8, after_dot: bool) {
+        self.has_digits = true;
+        if self.digits < MAX_DIGITS {
+            if self.mantissa != 0 || digit != 0 {
+                self.mantissa = self.mantissa * 10 + digit as u64;
+                self.digits += 1;
+            }
+            if after_dot {
+                self.adjust -= 1;
+            }
+        } else {
+            self.truncated |= digit != 0;
+            if !after_dot {
+                self.adjust += 1;
+            }
+        }
+    }
+
+    #[inline]
+    fn push_exponent_digit(&mut self, digit: u8) {
+        if self.exponent < MAX_EXPONENT {
+            self.exponent = self.exponent * 10 + digit as i64;
+        }
+    }
+
+    /// Update with a character accepted by the pattern, which is now in `state`.
+    #[inline]
+    fn update(&mut self, state: Float, c: u8) {
+        match state {
+            Float::Sign => self.negative = c == b'-',
+            Float::Digits => self.push_digit(c - b'0', false),
+            Float::DigitsAfterDot => self.push_digit(c - b'0', true),
+            Float::SignAfterExponent => self.exponent_negative = c == b'-',
+            Float::DigitAfterExponent => self.push_exponent_digit(c - b'0'),
+            _ => {}
+        }
+    }
+
+    /// Convert to a float.
+    ///
+    /// Returns [None] if the conversion cannot be decided.
+    #[inline]
+    fn to_float<F: RawFloat>(self, state: Float) -> Option<F> {
+        let value = match state {
+            Float::Inf | Float::Infinity => F::INFINITY,
+            Float::Nan => F::NAN,
+            _ => {
+                let exponent = if self.exponent_negative {
+                    -self.exponent
+                } else {
+                    self.exponent
+                };
+                lemire::convert(self.adjust + exponent, self.mantissa, self.truncated)?
+            }
+        };
+        Some(if self.negative { value.neg() } else { value })
+    }
+}
+
+/// Result of scanning a float at the start of a string.
+#[derive(Debug, Clone, Copy)]
+enum Scanned {
+    /// A token that matches the pattern, the final state, and the length of the token in bytes.
+    Value(Accumulator, Float, usize),
+    /// A token that does not match the pattern, and its length in bytes.
+    Invalid(usize),
+    /// The string does not start with a float pattern.
+    /// Contains the length of the matched prefix, including the unexpected character.
+    #[cfg(feature = "c-compatible")]
+    Unexpected(usize),
+}
+
+/// A decimal number `mantissa * 10^exponent` scanned by [scan_decimal].
+#[derive(Debug, Clone, Copy)]
+struct Decimal {
+    negative: bool,
+    mantissa: u64,
+    exponent: i64,
+}
+
+impl Decimal {
+    /// Convert to a float.
+    ///
+    /// Returns [None] if the conversion cannot be decided.
+    #[inline]
+    fn to_float<F: RawFloat>(self) -> Option<F> {
+        let value: F = lemire::convert(self.exponent, self.mantissa, false)?;
+        Some(if self.negative { value.neg() } else { value })
+    }
+}
+
+/// Scan `Digit*` at the start of `bytes`, and accumulate them into `mantissa` with wrapping.
+///
+/// Returns the number of digits.
+#[inline]
+fn scan_digits(bytes: &[u8], mantissa: &mut u64) -> usize {
+    let mut len = 0;
+    while let Some(&byte) = bytes.get(len) {
+        let digit = byte.wrapping_sub(b'0');
+        if digit >= 10 {
+            break;
+        }
+        *mantissa = mantissa.wrapping_mul(10).wrapping_add(digit as u64);
+        len += 1;
+    }
+    len
+}
+
+/// Scan `Sign? (Digit+ | Digit+ Dot Digit* | Digit* Dot Digit+) ('e' Sign? Digit+)?` at the start of `line`,
+/// with at most [MAX_DIGITS] digits before the exponent.
+///
+/// Returns the number and the length of the token, or [None] if [scan] has to decide.
+/// This happens for other forms, more digits, and signs, dots or exponent marks in `skip`.
+#[inline]
+fn scan_decimal<C: CharSet<Item = char>>(line: &str, skip: &C) -> Option<(Decimal, usize)> {
+    let bytes = line.as_bytes();
+    let is_mark = |cursor: usize, marks: &[u8]| {
+        bytes
+            .get(cursor)
+            .is_some_and(|&byte| marks.contains(&byte) && !skip.matches(byte as char))
+    };
+    let mut cursor = 0;
+    let negative = is_mark(cursor, b"-");
+    if negative || is_mark(cursor, b"+") {
+        cursor += 1;
+    }
+    let mut mantissa = 0;
+    let integer_digits = scan_digits(&bytes[cursor..], &mut mantissa);
+    cursor += integer_digits;
+    let mut fraction_digits = 0;
+    if is_mark(cursor, b".") {
+        cursor += 1;
+        fraction_digits = scan_digits(&bytes[cursor..], &mut mantissa);
+        cursor += fraction_digits;
+    }
+    let digits = integer_digits + fraction_digits;
+    if digits == 0 || digits > MAX_DIGITS as usize {
+        return None;
+    }
+    let mut exponent = 0;
+    if is_mark(cursor, b"eE") {
+        cursor += 1;
+        let exponent_negative = is_mark(cursor, b"-");
+        if exponent_negative || is_mark(cursor, b"+") {
+            cursor += 1;
+        }
+        let start = cursor;
+        while let Some(&byte) = bytes.get(cursor) {
+            let digit = byte.wrapping_sub(b'0');
+            if digit >= 10 {
+                break;
+            }
+            if exponent < MAX_EXPONENT {
+                exponent = exponent * 10 + digit as i64;
+            }
+            cursor += 1;
+        }
+        if cursor == start {
+            return None;
+        }
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+    let decimal = Decimal {
+        negative,
+        mantissa,
+        exponent: exponent - fraction_digits as i64,
+    };
+    Some((decimal, cursor))
+}
+
+/// Whether the token scanned by [scan_decimal] ends at `cursor` as [scan] decides.
+#[inline]
+fn ends_at<C: CharSet<Item = char>>(line: &str, cursor: usize, skip: &C) -> bool {
+    #[cfg(feature = "c-compatible")]
+    {
+        // The pattern cannot go on after the token.
+        let _ = (line, cursor, skip);
+        true
+    }
+    #[cfg(not(feature = "c-compatible"))]
+    {
+        line.as_bytes()
+            .get(cursor)
+            .is_none_or(|&byte| byte.is_ascii() && skip.matches(byte as char))
+    }
+}
+
+/// Scan the float token at the start of `line` with the [Float] pattern.
+///
+/// Without feature `c-compatible`, the token ends at the first character in `skip`.
+/// With feature `c-compatible`, the token is the longest prefix that matches the pattern.
+///
+/// Digits are never treated as separators.
+#[cold]
+fn scan<C: CharSet<Item = char>>(line: &str, skip: C) -> Scanned {
+    let bytes = line.as_bytes();
+    let mut state = Float::Initial;
+    let mut accumulator = Accumulator::default();
+    let mut last_stoppable = None;
+    let mut cursor = 0;
+    let failed = loop {
+        let Some(&byte) = bytes.get(cursor) else {
+            break None;
+        };
+        if !byte.is_ascii()
+            || !byte.is_ascii_digit() && skip.matches(byte as char)
+            || !state.step(byte as char)
+        {
+            break Some(cursor);
+        }
+        accumulator.update(state, byte);
+        cursor += 1;
+        if state.state() == State::Stoppable {
+            last_stoppable = Some((accumulator, state, cursor));
+        }
+    };
+
+    // Only ASCII characters are consumed, so `cursor` is a character boundary.
+    #[cfg(feature = "c-compatible")]
+    let end = {
+        let _ = skip;
+        failed.map_or(line.len(), |cursor| {
+            cursor + line[cursor..].chars().next().map_or(0, char::len_utf8)
+        })
+    };
+    #[cfg(not(feature = "c-compatible"))]
+    let end = failed.map_or(line.len(), |cursor| {
+        let rest = &line[cursor..];
+        cursor + skip.find_first_matching(rest).unwrap_or(rest.len())
+    });
+
+    match last_stoppable {
+        #[cfg(not(feature = "c-compatible"))]
+        Some((_, _, len)) if len != end => Scanned::Invalid(end),
+        Some((accumulator, state, len)) => {
+            // The pattern accepts `".e1"`, which has no digits in the mantissa.
+            if accumulator.has_digits || matches!(state, Float::Inf | Float::Infinity | Float::Nan)
+            {
+                Scanned::Value(accumulator, state, len)
+            } else {
+                Scanned::Invalid(len)
+            }
+        }
+        #[cfg(feature = "c-compatible")]
+        None => Scanned::Unexpected(end),
+        #[cfg(not(feature = "c-compatible"))]
+        None => Scanned::Invalid(end),
+    }
+}
+
+/// Parse with [FromStr], for errors and undecidable conversions.
+#[cold]
+fn fallback<T: FromStr<Err = ParseFloatError>>(s: &str) -> Result<T, ReadError<ParseFloatError>> {
+    s.parse()
+        .map_err(|err| ReadError::FromStrError(err, s.to_owned(), type_name::<T>()))
+}
+
+/// Parse a whole string into a float.
+#[inline]
+fn parse<T>(s: &str) -> Result<T, ReadError<ParseFloatError>>
+where
+    T: RawFloat + FromStr<Err = ParseFloatError>,
+{
+    let no_skip: &[char] = &[];
+    if let Some((decimal, len)) = scan_decimal(s, &no_skip) {
+        if len == s.len() {
+            if let Some(value) = decimal.to_float() {
+                return Ok(value);
+            }
+        }
+    }
+    match scan(s, no_skip) {
+        Scanned::Value(accumulator, state, len) if len == s.len() => {
+            match accumulator.to_float(state) {
+                Some(value) => Ok(value),
+                None => fallback(s),
+            }
+        }
+        _ => fallback(s),
+    }
+}
+
+/// Read a float from `stream`, accumulating the decimal number while scanning the buffer.
+#[inline]
+fn try_read_from<T, F, S>(stream: &mut S, format: F) -> Result<T, ReadError<ParseFloatError>>
+where
+    T: RawFloat + FromStr<Err = ParseFloatError>,
+    F: Format,
+    S: BufReadExt + ?Sized,
+{
+    let skip = format.skip();
+    let _: usize = stream.try_skip_all(skip)?;
+    let line = stream.get_line()?;
+    if let Some((decimal, len)) = scan_decimal(line, &skip) {
+        if ends_at(line, len, &skip) {
+            if let Some(value) = decimal.to_float() {
+                unsafe { stream.skip(len) };
+                return Ok(value);
+            }
+        }
+    }
+    let (len, result) = match scan(line, skip) {
+        Scanned::Value(accumulator, state, len) => (
+            len,
+            match accumulator.to_float(state) {
+                Some(value) => Ok(value),
+                None => fallback(&line[..len]),
+            },
+        ),
+        Scanned::Invalid(len) => (len, fallback(&line[..len])),
+        #[cfg(feature = "c-compatible")]
+        Scanned::Unexpected(len) => {
+            return Err(ReadError::UnexpectedChar(
+                line[..len].to_owned(),
+                type_name::<T>(),
+            ))
+        }
+    };
+    unsafe { stream.skip(len) };
+    result
+}
+
+macro_rules! impl_read_one_from_for_float {
+    ($($ty:ty)*) => {
+        $(
+            // See <https://doc.rust-lang.org/std/primitive.f64.html#impl-FromStr-for-f64>
+            impl crate::ReadOneFrom for $ty {
+                type ParseError = ParseFloatError;
 
-#[cfg(not(feature = "c-compatible"))]
-impl_read_one_from_for_from_str!(f32 f64);
+                #[cfg(feature = "c-compatible")]
+                #[inline]
+                fn accept() -> impl Pattern<Item = char> {
+                    Float::Initial
+                }
+
+                #[inline]
+                fn parse(s: &str) -> Result<Self, crate::ReadOneFromError<Self>> {
+                    parse(s)
+                }
+
+                #[inline]
+                fn try_read_one_from<F: Format, S: BufReadExt>(
+                    stream: &mut S,
+                    format: F,
+                ) -> Result<Self, crate::ReadOneFromError<Self>> {
+                    try_read_from(stream, format)
+                }
+
+                #[inline]
+                fn try_read_one_in_place_from<F: Format, S: BufReadExt>(
+                    &mut self,
+                    stream: &mut S,
+                    format: F,
+                ) -> Result<(), crate::ReadOneFromError<Self>> {
+                    *self = try_read_from(stream, format)?;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_read_one_from_for_float!(f32 f64);
+
+#[cfg(test)]
+mod tests {
+    use super::{ends_at, scan, scan_decimal, Scanned};
+    use crate::fmt::{Default, Format};
+
+    fn scan_default(s: &str) -> Option<(f64, usize)> {
+        match scan(s, Default::<char>::new().skip()) {
+            Scanned::Value(accumulator, state, len) => Some((accumulator.to_float(state)?, len)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(scan_default("1.5 2"), Some((1.5, 3)));
+        assert_eq!(scan_default("-0.001e3\n"), Some((-1.0, 8)));
+        assert_eq!(scan_default(".5e-1"), Some((0.05, 5)));
+        assert_eq!(
+            scan_default("+12345678901234567890123"),
+            Some((1.2345678901234568e22, 24))
+        );
+        assert_eq!(scan_default("1e400"), Some((f64::INFINITY, 5)));
+        assert_eq!(scan_default("-1e-400"), Some((-0.0, 7)));
+        assert_eq!(scan_default("-Infinity"), Some((f64::NEG_INFINITY, 9)));
+        assert!(scan_default("NaN").is_some_and(|(value, len)| value.is_nan() && len == 3));
+    }
+
+    #[test]
+    fn decimal_agrees_with_pattern() {
+        let skips: [&[char]; 3] = [&[' '], &[' ', '.'], &[' ', '-', 'e']];
+        for line in [
+            "1.5 2", "-0.25e-3", "1-2", "1.5", "2e5", "-3 4", "12.", ".5e1 ",
+        ] {
+            for skip in skips {
+                let Some((decimal, len)) = scan_decimal(line, &skip) else {
+                    continue;
+                };
+                if !ends_at(line, len, &skip) {
+                    continue;
+                }
+                let Scanned::Value(accumulator, state, expected_len) = scan(line, skip) else {
+                    panic!("{line:?} with {skip:?} is not a value");
+                };
+                assert_eq!(len, expected_len, "{line:?} with {skip:?}");
+                assert_eq!(
+                    decimal.to_float::<f64>(),
+                    accumulator.to_float::<f64>(state),
+                    "{line:?} with {skip:?}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn no_digits() {
+        assert!(matches!(
+            scan(".e1", Default::<char>::new().skip()),
+            Scanned::Invalid(3)
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+//! Conversion from decimal numbers to binary floating-point numbers.
+//!
+//! This is the Eisel-Lemire algorithm, with Clinger's fast path for small numbers.
+//! See <https://arxiv.org/abs/2101.11408> and <https://doi.org/10.1145/93542.93557>.
+
+use table::{POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+
+mod table;
+
+/// Binary floating-point types that decimal numbers can be converted to.
+pub(super) trait RawFloat: Copy {
+    /// Number of explicit bits in the mantissa.
+    const MANTISSA_EXPLICIT_BITS: i32;
+    /// Exponent bias.
+    const MINIMUM_EXPONENT: i32;
+    /// Biased exponent of infinity.
+    const INFINITE_POWER: i32;
+    /// Decimal exponents below this always round to zero.
+    const SMALLEST_POWER_OF_TEN: i64;
+    /// Decimal exponents above this always round to infinity.
+    const LARGEST_POWER_OF_TEN: i64;
+    /// Smallest decimal exponent where ties may be exact.
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64;
+    /// Largest decimal exponent where ties may be exact.
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64;
+    /// Largest decimal exponent whose power of ten is exact.
+    const MAX_EXPONENT_FAST_PATH: i64;
+    /// Largest mantissa that is exact.
+    const MAX_MANTISSA_FAST_PATH: u64 = 2 << Self::MANTISSA_EXPLICIT_BITS;
+
+    /// Positive infinity.
+    const INFINITY: Self;
+    /// Positive zero.
+    const ZERO: Self;
+    /// Not a number.
+    const NAN: Self;
+
+    /// Convert an exact integer.
+    fn from_u64(v: u64) -> Self;
+    /// Get an exact power of ten, `exponent <= MAX_EXPONENT_FAST_PATH`.
+    fn pow10_fast_path(exponent: usize) -> Self;
+    /// Build from the biased exponent and the mantissa without the hidden bit.
+    fn from_biased(mantissa: u64, exponent: i32) -> Self;
+    /// Multiply two numbers.
+    fn mul(self, rhs: Self) -> Self;
+    /// Divide two numbers.
+    fn div(self, rhs: Self) -> Self;
+    /// Negate a number.
+    fn neg(self) -> Self;
+}
+
+impl RawFloat for f32 {
+    const MANTISSA_EXPLICIT_BITS: i32 = 23;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const INFINITE_POWER: i32 = 0xFF;
+    const SMALLEST_POWER_OF_TEN: i64 = -65;
+    const LARGEST_POWER_OF_TEN: i64 = 38;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -17;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 10;
+    const MAX_EXPONENT_FAST_PATH: i64 = 10;
+
+    const INFINITY: Self = f32::INFINITY;
+    const ZERO: Self = 0.0;
+    const NAN: Self = f32::NAN;
+
+    #[inline]
+    fn from_u64(v: u64) -> Self {
+        v as f32
+    }
+    #[inline]
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+        TABLE[exponent]
+    }
+    #[inline]
+    fn from_biased(mantissa: u64, exponent: i32) -> Self {
+        f32::from_bits((mantissa | (exponent as u64) << Self::MANTISSA_EXPLICIT_BITS) as u32)
+    }
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+    #[inline]
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+impl RawFloat for f64 {
+    const MANTISSA_EXPLICIT_BITS: i32 = 52;
+    const MINIMUM_EXPONENT: i32 = -1023;
+    const INFINITE_POWER: i32 = 0x7FF;
+    const SMALLEST_POWER_OF_TEN: i64 = -342;
+    const LARGEST_POWER_OF_TEN: i64 = 308;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -4;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 23;
+    const MAX_EXPONENT_FAST_PATH: i64 = 22;
+
+    const INFINITY: Self = f64::INFINITY;
+    const ZERO: Self = 0.0;
+    const NAN: Self = f64::NAN;
+
+    #[inline]
+    fn from_u64(v: u64) -> Self {
+        v as f64
+    }
+    #[inline]
+    fn pow10_fast_path(exponent: usize) -> Self {
+        const TABLE: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+        ];
+        TABLE[exponent]
+    }
+    #[inline]
+    fn from_biased(mantissa: u64, exponent: i32) -> Self {
+        f64::from_bits(mantissa | (exponent as u64) << Self::MANTISSA_EXPLICIT_BITS)
+    }
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+    #[inline]
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
+/// A float as its biased exponent and its mantissa without the hidden bit.
+///
+/// A negative exponent means the result cannot be decided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BiasedFp {
+    f: u64,
+    e: i32,
+}
+
+impl BiasedFp {
+    const fn zero_pow2(e: i32) -> Self {
+        Self { f: 0, e }
+    }
+}
+
+/// Compute `floor(log2(10^q)) + 63`.
+#[inline]
+fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+#[inline]
+fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    let r = a as u128 * b as u128;
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Compute `w * 5^q` with at least `precision` correct bits in the high word.
+#[inline]
+fn compute_product_approx(q: i64, w: u64, precision: i32) -> (u64, u64) {
+    let mask = if precision < 64 {
+        u64::MAX >> precision
+    } else {
+        u64::MAX
+    };
+    let index = (q - SMALLEST_POWER_OF_FIVE) as usize;
+    let (hi5, lo5) = POWER_OF_FIVE_128[index];
+    let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+    if first_hi & mask == mask {
+        let (_, second_hi) = full_multiplication(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}
+
+/// Compute `w * 10^q` with the Eisel-Lemire algorithm.
+fn compute_float<F: RawFloat>(q: i64, mut w: u64) -> BiasedFp {
+    let fp_zero = BiasedFp::zero_pow2(0);
+    let fp_inf = BiasedFp::zero_pow2(F::INFINITE_POWER);
+    let fp_error = BiasedFp::zero_pow2(-1);
+
+    if w == 0 || q < F::SMALLEST_POWER_OF_TEN {
+        return fp_zero;
+    } else if q > F::LARGEST_POWER_OF_TEN {
+        return fp_inf;
+    }
+    let lz = w.leading_zeros();
+    w <<= lz;
+    let (lo, hi) = compute_product_approx(q, w, F::MANTISSA_EXPLICIT_BITS + 3);
+    if lo == u64::MAX && !(-27..=55).contains(&q) {
+        // The approximation of `5^q` may be too small by one,
+        // which could carry over the halfway point.
+        return fp_error;
+    }
+    let upper_bit = (hi >> 63) as i32;
+    let shift = upper_bit + 64 - F::MANTISSA_EXPLICIT_BITS - 3;
+    let mut mantissa = hi >> shift;
+    let mut power2 = power(q as i32) + upper_bit - lz as i32 - F::MINIMUM_EXPONENT;
+    if power2 <= 0 {
+        if -power2 + 1 >= 64 {
+            return fp_zero;
+        }
+        // Subnormal.
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        power2 = (mantissa >= 1 << F::MANTISSA_EXPLICIT_BITS) as i32;
+        return BiasedFp {
+            f: mantissa,
+            e: power2,
+        };
+    }
+    if lo <= 1
+        && (F::MIN_EXPONENT_ROUND_TO_EVEN..=F::MAX_EXPONENT_ROUND_TO_EVEN).contains(&q)
+        && mantissa & 3 == 1
+        && mantissa << shift == hi
+    {
+        // Exactly halfway, round down to even.
+        mantissa &= !1;
+    }
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= 2 << F::MANTISSA_EXPLICIT_BITS {
+        mantissa = 1 << F::MANTISSA_EXPLICIT_BITS;
+        power2 += 1;
+    }
+    mantissa &= !(1 << F::MANTISSA_EXPLICIT_BITS);
+    if power2 >= F::INFINITE_POWER {
+        return fp_inf;
+    }
+    BiasedFp {
+        f: mantissa,
+        e: power2,
+    }
+}
+
+/// Clinger's fast path, for exact mantissas and exact powers of ten.
+#[inline]
+fn try_fast_path<F: RawFloat>(q: i64, w: u64) -> Option<F> {
+    if !(-F::MAX_EXPONENT_FAST_PATH..=F::MAX_EXPONENT_FAST_PATH).contains(&q)
+        || w > F::MAX_MANTISSA_FAST_PATH
+    {
+        return None;
+    }
+    let value = F::from_u64(w);
+    Some(if q < 0 {
+        value.div(F::pow10_fast_path(-q as usize))
+    } else {
+        value.mul(F::pow10_fast_path(q as usize))
+    })
+}
+
+/// Convert `w * 10^q` to the nearest float, rounding ties to even.
+///
+/// If `truncated` is set, `w` has been truncated from a longer mantissa,
+/// and the exact value is between `w * 10^q` and `(w + 1) * 10^q`.
+///
+/// Returns [None] if the result cannot be decided from `w` and `q`.
+#[inline]
+pub(super) fn convert<F: RawFloat>(q: i64, w: u64, truncated: bool) -> Option<F> {
+    if w == 0 {
+        return Some(F::ZERO);
+    }
+    if !truncated {
+        if let Some(value) = try_fast_path(q, w) {
+            return Some(value);
+        }
+    }
+    let fp = compute_float::<F>(q, w);
+    if fp.e < 0 || truncated && fp != compute_float::<F>(q, w + 1) {
+        return None;
+    }
+    if fp.e >= F::INFINITE_POWER {
+        return Some(F::INFINITY);
+    }
+    Some(F::from_biased(fp.f, fp.e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        convert,
+        table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE},
+    };
+
+    /// Little-endian limbs of an unsigned big integer.
+    type Limbs = Vec<u32>;
+
+    #[inline]
+    fn bit_length(limbs: &[u32]) -> usize {
+        limbs
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .map_or(0, |i| i * 32 + 32 - limbs[i].leading_zeros() as usize)
+    }
+
+    fn mul_small(limbs: &mut Limbs, factor: u32) {
+        let mut carry = 0u64;
+        for limb in limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+    }
+
+    fn div_small(limbs: &mut Limbs, divisor: u32) {
+        let mut remainder = 0u64;
+        for limb in limbs.iter_mut().rev() {
+            let dividend = remainder << 32 | *limb as u64;
+            *limb = (dividend / divisor as u64) as u32;
+            remainder = dividend % divisor as u64;
+        }
+    }
+
+    /// Get the 128 most significant bits, truncating the rest.
+    fn top_128_bits(limbs: &[u32]) -> u128 {
+        let len = bit_length(limbs);
+        let shift = len.saturating_sub(128);
+        let mut value = 0u128;
+        for i in (0..128.min(len)).rev() {
+            let bit = shift + i;
+            value = value << 1 | (limbs[bit / 32] >> (bit % 32) & 1) as u128;
+        }
+        value << (128 - len.min(128))
+    }
+
+    fn power_of_five_128(q: i64) -> (u64, u64) {
+        let mut power = vec![1];
+        for _ in 0..q.unsigned_abs() {
+            mul_small(&mut power, 5);
+        }
+        let value = if q >= 0 {
+            top_128_bits(&power)
+        } else {
+            // `5^-q` is never a power of two, so `2^(z - 1) < 5^-q < 2^z`.
+            let z = bit_length(&power);
+            let b = if q >= -27 { z + 127 } else { 2 * z + 128 };
+            let mut quotient = vec![0; b / 32 + 1];
+            quotient[b / 32] = 1 << (b % 32);
+            for _ in 0..-q {
+                div_small(&mut quotient, 5);
+            }
+            // Adding one never carries into a new limb here.
+            quotient[0] += 1;
+            top_128_bits(&quotient)
+        };
+        ((value >> 64) as u64, value as u64)
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(
+            power_of_five_128(SMALLEST_POWER_OF_FIVE),
+            (0xeef453d6923bd65a, 0x113faa2906a13b3f),
+        );
+        assert_eq!(
+            power_of_five_128(-1),
+            (0xcccccccccccccccc, 0xcccccccccccccccd),
+        );
+        assert_eq!(power_of_five_128(0), (0x8000000000000000, 0));
+        assert_eq!(power_of_five_128(1), (0xa000000000000000, 0));
+        assert_eq!(
+            power_of_five_128(LARGEST_POWER_OF_FIVE),
+            (0x8e679c2f5e44ff8f, 0x570f09eaa7ea7648),
+        );
+        for (q, &entry) in (SMALLEST_POWER_OF_FIVE..=LARGEST_POWER_OF_FIVE).zip(&POWER_OF_FIVE_128)
+        {
+            assert_eq!(entry, power_of_five_128(q), "5^{q}");
+        }
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(convert::<f64>(0, 1, false), Some(1.0));
+        assert_eq!(convert::<f64>(-1, 1, false), Some(0.1));
+        assert_eq!(
+            convert::<f64>(308, 17976931348623157, false),
+            Some(f64::INFINITY)
+        );
+        assert_eq!(
+            convert::<f64>(292, 17976931348623157, false),
+            Some(f64::MAX)
+        );
+        assert_eq!(
+            convert::<f64>(-324, 22250738585072014, false),
+            Some(f64::MIN_POSITIVE)
+        );
+        assert_eq!(convert::<f64>(-324, 5, false), Some(f64::from_bits(1)));
+        assert_eq!(convert::<f64>(-400, 1, false), Some(0.0));
+        assert_eq!(convert::<f32>(38, 4, false), Some(f32::INFINITY));
+        assert_eq!(convert::<f32>(-1, 1, false), Some(0.1));
+        assert_eq!(convert::<f32>(-45, 1, false), Some(f32::from_bits(1)));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{impl_read_one_from_for_from_str, Index1, ReadOneFrom};
+
+macro_rules! impl_read_one_from_for_index1 {
+    ($($ty:ty)*) => {
+        $(
+            impl_read_one_from_for_from_str!(Index1<$ty> => <$ty as ReadOneFrom>::accept());
+        )*
+    };
+}
+
+impl_read_one_from_for_index1!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
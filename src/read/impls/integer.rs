@@ -0,0 +1,287 @@
+//! Direct integer reader.
+//!
+//! Integers are accumulated digit by digit while the stream buffer is scanned,
+//! so the token is only walked once. [FromStr] is only called on the error path,
+//! to produce exactly the same [ParseIntError] as [str::parse] does.
+
+use crate::{
+    fmt::Format,
+    stream::{ext::CharSet, traits::BufReadExtWithFormat},
+    BufReadExt, ReadError,
+};
+use std::{any::type_name, num::ParseIntError, str::FromStr};
+
+/// Primitive integer types that can be accumulated digit by digit.
+pub(super) trait Integer: Copy {
+    /// Whether a leading `'-'` is accepted.
+    const SIGNED: bool;
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// Compute `self * 10 + digit`, or [None] on overflow.
+    fn push_digit(self, digit: u8) -> Option<Self>;
+
+    /// Compute `self * 10 - digit`, or [None] on overflow.
+    fn push_digit_negative(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal $($ty:ty)*) => {
+        $(
+            impl Integer for $ty {
+                const SIGNED: bool = $signed;
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn push_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_add(digit as Self)
+                }
+
+                #[inline]
+                fn push_digit_negative(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_sub(digit as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false u8 u16 u32 u64 u128 usize);
+impl_integer!(true i8 i16 i32 i64 i128 isize);
+
+/// Result of scanning an integer at the start of a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scanned<T> {
+    /// A valid integer, and the length of its token in bytes.
+    Value(T, usize),
+    /// A token that cannot be converted to an integer, and its length in bytes.
+    Invalid(usize),
+    /// The string does not start with an integer pattern.
+    /// Contains the length of the matched prefix, including the unexpected character.
+    #[cfg(feature = "c-compatible")]
+    Unexpected(usize),
+}
+
+/// Scan the integer token at the start of `line`.
+///
+/// Without feature `c-compatible`, the token ends at the first character in `skip`.
+/// With feature `c-compatible`, the token ends at the first character that cannot extend the integer.
+///
+/// Digits are never treated as separators.
+#[inline]
+fn scan<T: Integer, C: CharSet<Item = char>>(line: &str, skip: C) -> Scanned<T> {
+    let bytes = line.as_bytes();
+    let (negative, start) = match bytes.first() {
+        Some(b'+') => (false, 1),
+        Some(b'-') if T::SIGNED => (true, 1),
+        _ => (false, 0),
+    };
+
+    let mut value = Some(T::ZERO);
+    let mut end = start;
+    while let Some(&byte) = bytes.get(end) {
+        let digit = byte.wrapping_sub(b'0');
+        if digit >= 10 {
+            break;
+        }
+        value = value.and_then(|value| {
+            if negative {
+                value.push_digit_negative(digit)
+            } else {
+                value.push_digit(digit)
+            }
+        });
+        end += 1;
+    }
+    let digits = end - start;
+
+    #[cfg(feature = "c-compatible")]
+    {
+        let _ = skip;
+        if digits == 0 {
+            let unexpected = line[end..].chars().next().map_or(0, char::len_utf8);
+            return Scanned::Unexpected(end + unexpected);
+        }
+    }
+
+    #[cfg(not(feature = "c-compatible"))]
+    {
+        // Only ASCII digits and signs are consumed, so `end` is a character boundary.
+        let rest = &line[end..];
+        match rest.chars().next() {
+            Some(c) if !skip.matches(c) => {
+                let len = skip.find_first_matching(rest).unwrap_or(rest.len());
+                return Scanned::Invalid(end + len);
+            }
+            _ if digits == 0 => return Scanned::Invalid(end),
+            _ => {}
+        }
+    }
+
+    match value {
+        Some(value) => Scanned::Value(value, end),
+        None => Scanned::Invalid(end),
+    }
+}
+
+/// Create the error for an invalid integer token.
+#[cold]
+fn invalid<T: FromStr<Err = ParseIntError>>(s: &str) -> ReadError<ParseIntError> {
+    match s.parse::<T>() {
+        Err(err) => ReadError::FromStrError(err, s.to_owned(), type_name::<T>()),
+        Ok(_) => unreachable!("{s:?} should not be a valid `{}`", type_name::<T>()),
+    }
+}
+
+/// Parse a whole string into an integer.
+#[inline]
+pub(super) fn parse<T, P>(s: &str) -> Result<T, ReadError<ParseIntError>>
+where
+    T: FromStr<Err = ParseIntError> + TryFrom<P>,
+    P: Integer,
+{
+    let no_skip: &[char] = &[];
+    match scan::<P, _>(s, no_skip) {
+        Scanned::Value(value, len) if len == s.len() => {
+            T::try_from(value).map_err(|_| invalid::<T>(s))
+        }
+        _ => Err(invalid::<T>(s)),
+    }
+}
+
+/// Read an integer from `stream`, accumulating digits while scanning the buffer.
+#[inline]
+pub(super) fn try_read_from<T, P, F, S>(
+    stream: &mut S,
+    format: F,
+) -> Result<T, ReadError<ParseIntError>>
+where
+    T: FromStr<Err = ParseIntError> + TryFrom<P>,
+    P: Integer,
+    F: Format,
+    S: BufReadExt + ?Sized,
+{
+    let skip = format.skip();
+    let _: usize = stream.try_skip_all(skip)?;
+    let line = stream.get_line()?;
+    let (len, result) = match scan::<P, _>(line, skip) {
+        Scanned::Value(value, len) => (
+            len,
+            T::try_from(value).map_err(|_| invalid::<T>(&line[..len])),
+        ),
+        Scanned::Invalid(len) => (len, Err(invalid::<T>(&line[..len]))),
+        #[cfg(feature = "c-compatible")]
+        Scanned::Unexpected(len) => {
+            return Err(ReadError::UnexpectedChar(
+                line[..len].to_owned(),
+                type_name::<T>(),
+            ))
+        }
+    };
+    unsafe { stream.skip(len) };
+    result
+}
+
+/// Implement [ReadOneFrom] for integer types with the direct integer reader.
+///
+/// [ReadOneFrom]: crate::ReadOneFrom
+macro_rules! impl_read_one_from_for_integer {
+    (@ $ty:ty => $prim:ty { $($accept:tt)* }) => {
+        impl $crate::ReadOneFrom for $ty {
+            type ParseError = ::std::num::ParseIntError;
+
+            $($accept)*
+
+            #[inline]
+            fn parse(s: &str) -> Result<Self, $crate::ReadOneFromError<Self>> {
+                super::integer::parse::<Self, $prim>(s)
+            }
+
+            #[inline]
+            fn try_read_one_from<F: $crate::fmt::Format, S: $crate::BufReadExt>(
+                stream: &mut S,
+                format: F,
+            ) -> Result<Self, $crate::ReadOneFromError<Self>> {
+                super::integer::try_read_from::<Self, $prim, F, S>(stream, format)
+            }
+
+            #[inline]
+            fn try_read_one_in_place_from<F: $crate::fmt::Format, S: $crate::BufReadExt>(
+                &mut self,
+                stream: &mut S,
+                format: F,
+            ) -> Result<(), $crate::ReadOneFromError<Self>> {
+                *self = super::integer::try_read_from::<Self, $prim, F, S>(stream, format)?;
+                Ok(())
+            }
+        }
+    };
+    ($($ty:ty => $prim:ty)* ; accept = $accept:expr) => {
+        $(
+            impl_read_one_from_for_integer!(@ $ty => $prim {
+                #[inline]
+                fn accept() -> impl $crate::ext::Pattern<Item = char> {
+                    $accept
+                }
+            });
+        )*
+    };
+    ($($ty:ty => $prim:ty)*) => {
+        $(
+            impl_read_one_from_for_integer!(@ $ty => $prim {});
+        )*
+    };
+}
+
+pub(super) use impl_read_one_from_for_integer;
+
+#[cfg(test)]
+mod tests {
+    use super::{scan, Integer, Scanned};
+    use crate::fmt::{Default, Format};
+
+    fn scan_default<T: Integer>(s: &str) -> Scanned<T> {
+        scan(s, Default::<char>::new().skip())
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(scan_default::<u8>("255"), Scanned::Value(255, 3));
+        assert_eq!(scan_default::<u8>("256"), Scanned::Invalid(3));
+        assert_eq!(scan_default::<i8>("-128"), Scanned::Value(-128, 4));
+        assert_eq!(scan_default::<i8>("-129"), Scanned::Invalid(4));
+        assert_eq!(scan_default::<i8>("+127"), Scanned::Value(127, 4));
+        assert_eq!(scan_default::<i8>("128"), Scanned::Invalid(3));
+        assert_eq!(
+            scan_default::<u128>("340282366920938463463374607431768211455 "),
+            Scanned::Value(u128::MAX, 39),
+        );
+        assert_eq!(
+            scan_default::<i128>("-170141183460469231731687303715884105728\n"),
+            Scanned::Value(i128::MIN, 40),
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "c-compatible"))]
+    fn tokens() {
+        assert_eq!(scan_default::<u32>("12 34"), Scanned::Value(12, 2));
+        assert_eq!(scan_default::<u32>("12,34 5"), Scanned::Invalid(5));
+        assert_eq!(scan_default::<u32>("-1 2"), Scanned::Invalid(2));
+        assert_eq!(scan_default::<i32>("- 2"), Scanned::Invalid(1));
+        assert_eq!(scan_default::<i32>("+"), Scanned::Invalid(1));
+        assert_eq!(scan_default::<i32>("1🦀 2"), Scanned::Invalid(5));
+    }
+
+    #[test]
+    #[cfg(feature = "c-compatible")]
+    fn tokens() {
+        assert_eq!(scan_default::<u32>("12 34"), Scanned::Value(12, 2));
+        assert_eq!(scan_default::<u32>("12,34 5"), Scanned::Value(12, 2));
+        assert_eq!(scan_default::<u32>("-1 2"), Scanned::Unexpected(1));
+        assert_eq!(scan_default::<i32>("- 2"), Scanned::Unexpected(2));
+        assert_eq!(scan_default::<i32>("+"), Scanned::Unexpected(1));
+        assert_eq!(scan_default::<i32>("1🦀 2"), Scanned::Value(1, 1));
+        assert_eq!(scan_default::<i32>("+🦀"), Scanned::Unexpected(5));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    impl_read_one_from_for_from_str,
+    radix::{AutoRadix, Bin, Hex, Oct},
+};
+
+#[cfg(feature = "c-compatible")]
+mod inner {
+    use crate::{
+        ext::{Pattern, State},
+        radix::radix_of_prefix,
+    };
+
+    #[derive(Debug, Clone, Copy)]
+    enum Step {
+        Initial,
+        Sign,
+        Zero,
+        Prefix,
+        Digits,
+    }
+
+    /// `Sign? ('0' Prefix)? Digit+`, where digits are in the radix.
+    #[derive(Debug, Clone, Copy)]
+    pub(super) struct Radix {
+        /// Current radix, which is 10 before any prefix is found if `auto` is set.
+        radix: u32,
+        /// Whether the radix is detected from the prefix.
+        auto: bool,
+        /// Whether `'-'` is accepted.
+        signed: bool,
+        step: Step,
+    }
+
+    impl Radix {
+        #[inline]
+        pub(super) const fn new(radix: Option<u32>, signed: bool) -> Self {
+            let (radix, auto) = match radix {
+                Some(radix) => (radix, false),
+                None => (10, true),
+            };
+            Self {
+                radix,
+                auto,
+                signed,
+                step: Step::Initial,
+            }
+        }
+
+        #[inline]
+        fn digit(&mut self, c: char) -> bool {
+            if c.is_digit(self.radix) {
+                self.step = if c == '0' && matches!(self.step, Step::Initial | Step::Sign) {
+                    Step::Zero
+                } else {
+                    Step::Digits
+                };
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    impl Pattern for Radix {
+        type Item = char;
+
+        #[inline]
+        fn step(&mut self, c: <Self as Pattern>::Item) -> bool {
+            match self.step {
+                Step::Initial => match c {
+                    '+' => self.step = Step::Sign,
+                    '-' if self.signed => self.step = Step::Sign,
+                    _ => return self.digit(c),
+                },
+                Step::Sign | Step::Prefix | Step::Digits => return self.digit(c),
+                Step::Zero => match radix_of_prefix(c) {
+                    Some(radix) if self.auto => {
+                        self.radix = radix;
+                        self.step = Step::Prefix;
+                    }
+                    Some(radix) if radix == self.radix => self.step = Step::Prefix,
+                    _ => return self.digit(c),
+                },
+            }
+            true
+        }
+
+        #[inline]
+        fn state(&self) -> State {
+            match self.step {
+                Step::Zero | Step::Digits => State::Stoppable,
+                Step::Initial | Step::Sign | Step::Prefix => State::Unfulfilled,
+            }
+        }
+    }
+}
+
+macro_rules! impl_read_one_from_for_radix {
+    ($($wrapper:ident $radix:expr)*) => {
+        $(
+            #[cfg(feature = "c-compatible")]
+            impl_read_one_from_for_from_str!(
+                $wrapper<i8> $wrapper<i16> $wrapper<i32> $wrapper<i64> $wrapper<i128> $wrapper<isize>
+                => inner::Radix::new($radix, true)
+            );
+            #[cfg(feature = "c-compatible")]
+            impl_read_one_from_for_from_str!(
+                $wrapper<u8> $wrapper<u16> $wrapper<u32> $wrapper<u64> $wrapper<u128> $wrapper<usize>
+                => inner::Radix::new($radix, false)
+            );
+            #[cfg(not(feature = "c-compatible"))]
+            impl_read_one_from_for_from_str!(
+                $wrapper<i8> $wrapper<i16> $wrapper<i32> $wrapper<i64> $wrapper<i128> $wrapper<isize>
+                $wrapper<u8> $wrapper<u16> $wrapper<u32> $wrapper<u64> $wrapper<u128> $wrapper<usize>
+            );
+        )*
+    };
+}
+
+impl_read_one_from_for_radix!(
+    Hex Some(16)
+    Oct Some(8)
+    Bin Some(2)
+    AutoRadix None
+);
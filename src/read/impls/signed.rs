@@ -1,4 +1,5 @@
-use crate::impl_read_one_from_for_from_str;
+use super::integer::impl_read_one_from_for_integer;
+use crate::{impl_read_one_from_for_from_str, BigInt};
 use std::num::*;
 
 #[cfg(feature = "c-compatible")]
@@ -65,37 +66,43 @@ mod inner {
 }
 
 #[cfg(feature = "c-compatible")]
-impl_read_one_from_for_from_str!(
-    i8
-    i16
-    i32
-    i64
-    i128
-    isize
+impl_read_one_from_for_integer!(
+    i8 => i8
+    i16 => i16
+    i32 => i32
+    i64 => i64
+    i128 => i128
+    isize => isize
 
-    NonZeroI8
-    NonZeroI16
-    NonZeroI32
-    NonZeroI64
-    NonZeroI128
-    NonZeroIsize
+    NonZeroI8 => i8
+    NonZeroI16 => i16
+    NonZeroI32 => i32
+    NonZeroI64 => i64
+    NonZeroI128 => i128
+    NonZeroIsize => isize
 
-    => inner::Signed::Initial
+    ; accept = inner::Signed::Initial
 );
 
 #[cfg(not(feature = "c-compatible"))]
-impl_read_one_from_for_from_str!(
-    i8
-    i16
-    i32
-    i64
-    i128
-    isize
+impl_read_one_from_for_integer!(
+    i8 => i8
+    i16 => i16
+    i32 => i32
+    i64 => i64
+    i128 => i128
+    isize => isize
 
-    NonZeroI8
-    NonZeroI16
-    NonZeroI32
-    NonZeroI64
-    NonZeroI128
-    NonZeroIsize
+    NonZeroI8 => i8
+    NonZeroI16 => i16
+    NonZeroI32 => i32
+    NonZeroI64 => i64
+    NonZeroI128 => i128
+    NonZeroIsize => isize
 );
+
+#[cfg(feature = "c-compatible")]
+impl_read_one_from_for_from_str!(BigInt => inner::Signed::Initial);
+
+#[cfg(not(feature = "c-compatible"))]
+impl_read_one_from_for_from_str!(BigInt);
@@ -1,4 +1,5 @@
-use crate::impl_read_one_from_for_from_str;
+use super::integer::impl_read_one_from_for_integer;
+use crate::{impl_read_one_from_for_from_str, BigUint, Digits};
 use std::num::*;
 
 #[cfg(feature = "c-compatible")]
@@ -62,40 +63,76 @@ mod inner {
             }
         }
     }
+
+    /// `Digit+`, without a sign.
+    #[derive(Debug, Clone, Copy)]
+    pub(super) enum DigitsOnly {
+        Initial,
+        Digits,
+    }
+
+    impl Pattern for DigitsOnly {
+        type Item = char;
+
+        #[inline]
+        fn step(&mut self, c: <Self as Pattern>::Item) -> bool {
+            let digit = c.is_ascii_digit();
+            if digit {
+                *self = Self::Digits;
+            }
+            digit
+        }
+
+        #[inline]
+        fn state(&self) -> State {
+            match self {
+                Self::Digits => State::Stoppable,
+                Self::Initial => State::Unfulfilled,
+            }
+        }
+    }
 }
 
 #[cfg(feature = "c-compatible")]
-impl_read_one_from_for_from_str!(
-    u8
-    u16
-    u32
-    u64
-    u128
-    usize
-
-    NonZeroU8
-    NonZeroU16
-    NonZeroU32
-    NonZeroU64
-    NonZeroU128
-    NonZeroUsize
-
-    => inner::Unsigned::Initial
+impl_read_one_from_for_integer!(
+    u8 => u8
+    u16 => u16
+    u32 => u32
+    u64 => u64
+    u128 => u128
+    usize => usize
+
+    NonZeroU8 => u8
+    NonZeroU16 => u16
+    NonZeroU32 => u32
+    NonZeroU64 => u64
+    NonZeroU128 => u128
+    NonZeroUsize => usize
+
+    ; accept = inner::Unsigned::Initial
 );
 
 #[cfg(not(feature = "c-compatible"))]
-impl_read_one_from_for_from_str!(
-    u8
-    u16
-    u32
-    u64
-    u128
-    usize
-
-    NonZeroU8
-    NonZeroU16
-    NonZeroU32
-    NonZeroU64
-    NonZeroU128
-    NonZeroUsize
+impl_read_one_from_for_integer!(
+    u8 => u8
+    u16 => u16
+    u32 => u32
+    u64 => u64
+    u128 => u128
+    usize => usize
+
+    NonZeroU8 => u8
+    NonZeroU16 => u16
+    NonZeroU32 => u32
+    NonZeroU64 => u64
+    NonZeroU128 => u128
+    NonZeroUsize => usize
 );
+
+#[cfg(feature = "c-compatible")]
+impl_read_one_from_for_from_str!(BigUint => inner::Unsigned::Initial);
+#[cfg(feature = "c-compatible")]
+impl_read_one_from_for_from_str!(Digits => inner::DigitsOnly::Initial);
+
+#[cfg(not(feature = "c-compatible"))]
+impl_read_one_from_for_from_str!(BigUint Digits);
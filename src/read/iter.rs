@@ -1,9 +1,11 @@
-use super::read_one_from::ReadOneFromError;
+use super::{read_from::ReadFromError, read_one_from::ReadOneFromError};
 use crate::{
     ext::PatternError,
     fmt::Format,
-    stream::{error::StreamError, line_buf::LineBuf, traits::BufReadExtWithFormat},
-    BufReadExt, ReadError, ReadOneFrom,
+    stream::{
+        block_buf::BlockBuf, error::StreamError, line_buf::LineBuf, traits::BufReadExtWithFormat,
+    },
+    BufReadExt, ReadError, ReadFrom, ReadOneFrom,
 };
 use std::{any::type_name, marker::PhantomData};
 
@@ -78,6 +80,62 @@ impl<F: Format, T: ReadOneFrom> Iterator for ReadAllIn<'_, F, T> {
     }
 }
 
+/// Iterator for elements returned by `read` until one does not satisfy `pred`.
+///
+/// The first element that does not satisfy `pred` is consumed but not yielded,
+/// and the iteration stops after it or after the first error.
+#[inline]
+pub(crate) fn iter_read_while<T, E>(
+    mut read: impl FnMut() -> Result<T, E>,
+    mut pred: impl FnMut(&T) -> bool,
+) -> impl Iterator<Item = Result<T, E>> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        match read() {
+            Ok(t) if pred(&t) => Some(Ok(t)),
+            Ok(_) => {
+                done = true;
+                None
+            }
+            Err(e) => {
+                done = true;
+                Some(Err(e))
+            }
+        }
+    })
+}
+
+/// Iterator for elements read by `T` from each block returned by `get_block`.
+///
+/// The iteration stops at the end of file, or after the first error.
+#[inline]
+pub(crate) fn iter_read_blocks<T: ReadFrom, F: Format>(
+    mut get_block: impl FnMut() -> Result<(usize, String), StreamError>,
+    format: F,
+) -> impl Iterator<Item = Result<T, ReadFromError<T>>> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let res = match get_block() {
+            Ok((first_line, block)) => {
+                T::try_read_from(&mut BlockBuf::new(&block, first_line), format)
+            }
+            Err(StreamError::Eof) => {
+                done = true;
+                return None;
+            }
+            Err(e) => Err(e.into()),
+        };
+        done = res.is_err();
+        Some(res)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,102 @@
+use super::{
+    collection::{Duplicate, ReadCollection},
+    fmt::Format,
+    read_from::ReadFromError,
+};
+use crate::{BufReadExt, ReadFrom};
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    ops::{Deref, DerefMut},
+};
+
+/// A collection preceded by its length.
+///
+/// It reads a [usize] first, and then reads that many elements into `C` with
+/// [ReadCollection::try_read_collection_n_from], no matter whether they are in the same line or not.
+/// Duplicate keys are handled with [Duplicate::default].
+///
+/// [`LenPrefixed<String>`] reads that many characters.
+///
+/// See [read!](crate::read!) for reading a jagged matrix whose rows are length-prefixed.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{InputStream, LenPrefixed, ReadInto};
+///
+/// let mut reader = InputStream::new("3 1 2 3\n5 hello\nbob 2 4\n5".as_bytes());
+///
+/// let a: LenPrefixed<Vec<u32>> = reader.read();
+/// assert_eq!(a.0, [1, 2, 3]);
+///
+/// let b: LenPrefixed<String> = reader.read();
+/// assert_eq!(*b, "hello");
+///
+/// let (name, c): (String, LenPrefixed<Vec<u32>>) = reader.read();
+/// assert_eq!(name, "bob");
+/// assert_eq!(c.into_inner(), [4, 5]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LenPrefixed<C>(pub C);
+
+impl<C> LenPrefixed<C> {
+    /// Unwrap the collection.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> Deref for LenPrefixed<C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C> DerefMut for LenPrefixed<C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.0
+    }
+}
+
+/// Error during parsing the length or an element of a [LenPrefixed].
+#[derive(Debug)]
+pub enum LenPrefixedError<E> {
+    /// Error during parsing the length.
+    Len(ParseIntError),
+    /// Error during parsing an element.
+    Item(E),
+}
+
+impl<E: std::error::Error> Display for LenPrefixedError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Len(err) => Display::fmt(err, f),
+            Self::Item(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for LenPrefixedError<E> {}
+
+impl<C: ReadCollection> ReadFrom for LenPrefixed<C> {
+    type ParseError = LenPrefixedError<<C::Item as ReadFrom>::ParseError>;
+
+    #[inline]
+    fn try_read_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+    ) -> Result<Self, ReadFromError<Self>> {
+        let n = usize::try_read_from(stream, format)
+            .map_err(|err| err.map_parse_error(LenPrefixedError::Len))?;
+        let collection = C::try_read_collection_n_from(stream, n, format, Duplicate::default())
+            .map_err(|err| err.map_parse_error(LenPrefixedError::Item))?;
+        Ok(Self(collection))
+    }
+}
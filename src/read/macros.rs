@@ -8,14 +8,32 @@
 ///
 /// - `read!()` reads a single data item from input.
 /// - `read!(n)` reads `n` data items from input and stores them in a [Vec].
-/// - `read!(m, n)` reads `m * n` data items from input and stores them in a [Mat],
-///   
-///   which consists of `m` [Vec]s, each containing `n` data items.
+/// - `read!(m, n)` reads `m * n` data items from input and stores them in a [Vec] of `m` [Vec]s, each containing `n` data items.
+/// - `read!(mat m, n)` reads `m * n` data items from input and stores them in a [Mat],
+///   which has `m` rows and `n` columns.
+///
+/// - `read!(..)` reads a length and then that many data items, and stores them in a [Vec]
+///   (or a [String] of characters, or any other collection that implements [ReadCollection]).
+/// - `read!(m, ..)` reads `m` rows, each of which starts with its own length, and stores them in a [Vec] of [Vec]s,
+///   which is a jagged matrix.
+/// - `read!(collect n)` reads `n` data items from input, and stores them in any collection that implements
+///   [ReadCollection], such as [HashSet] and [BTreeMap]. Duplicate keys are handled with the default [Duplicate] policy.
+/// - `read!(columns n)` reads `n` tuples from input, and stores each element of them in its own [Vec],
+///   such as `(Vec<A>, Vec<B>)` for `(A, B)`. See [ReadColumns].
+/// - `read!(until sentinel)` reads data items until `sentinel`, and stores them in a [Vec].
+///   The sentinel is consumed but not stored.
+/// - `read!(while pred)` reads data items while they satisfy `pred`, and stores them in a [Vec].
+///   The first data item that does not satisfy `pred` is consumed but not stored.
 ///
 /// And the reader will always respect your size hint, and if you pass a size larger that the actual data in current line, it will go on to the next line and read as many as the size hint.
 ///
 /// [Mat]: crate::Mat
 /// [ReadInto]: crate::ReadInto
+/// [ReadCollection]: crate::ReadCollection
+/// [Duplicate]: crate::Duplicate
+/// [ReadColumns]: crate::ReadColumns
+/// [HashSet]: std::collections::HashSet
+/// [BTreeMap]: std::collections::BTreeMap
 ///
 /// # Example
 ///
@@ -24,6 +42,13 @@
 /// let a: usize = read!();
 /// let b: Vec<usize> = read!(3);
 /// let c: Vec<Vec<usize>> = read!(2, 3);
+/// let mat: iof::Mat<usize> = read!(mat 2, 3);
+/// let set: std::collections::HashSet<usize> = read!(collect 3);
+/// let d: Vec<usize> = read!(..);
+/// let e: Vec<Vec<usize>> = read!(2, ..);
+/// let (f, g): (Vec<usize>, Vec<char>) = read!(columns 3);
+/// let h: Vec<usize> = read!(until 0);
+/// let i: Vec<i32> = read!(while |x: &i32| *x >= 0);
 /// ```
 ///
 /// # Notes
@@ -63,15 +88,15 @@
 ///
 /// let s = b"010\n101";
 ///
-/// let b: Mat<char> = read!(2, 3; src = InputStream::new(s.as_slice()); skip = [' ', ',', ';', '\n', '\r']);
+/// let b: Mat<char> = read!(mat 2, 3; src = InputStream::new(s.as_slice()); skip = [' ', ',', ';', '\n', '\r']);
 /// assert_eq!(b, [['0', '1', '0'], ['1', '0', '1']]);
 ///
 /// let s = b"1,2,3;4,5,6\r\n";
 ///
-/// let b: Mat<usize> = read!(2, 3; src = InputStream::new(s.as_slice()); skip = [' ', ',', ';', '\r', '\n']);
+/// let b: Mat<usize> = read!(mat 2, 3; src = InputStream::new(s.as_slice()); skip = [' ', ',', ';', '\r', '\n']);
 /// assert_eq!(b, [[1, 2, 3], [4, 5, 6]]);
 ///
-/// let b: Mat<usize> = read!(2, 3; src = InputStream::new(s.as_slice()); skip = " \t,;\r\n".chars());
+/// let b: Mat<usize> = read!(mat 2, 3; src = InputStream::new(s.as_slice()); skip = " \t,;\r\n".chars());
 /// assert_eq!(b, [[1, 2, 3], [4, 5, 6]]);
 /// ```
 #[macro_export]
@@ -79,6 +104,104 @@ macro_rules! read {
     (@ $(,)?; src = $src:expr; fmt = $fmt:expr) => {
         $crate::unwrap!($crate::ReadFrom::try_read_from($src, $fmt))
     };
+    (@ columns $n:expr; src = $src:expr; fmt = $fmt:expr) => {
+        $crate::unwrap!($crate::Columns::try_read_columns_from($src, $n, $fmt))
+    };
+    (columns $n:expr $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ columns $n; src = src; fmt = fmt)
+    }};
+    (columns $n:expr $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ columns $n; src = src; fmt = fmt)
+    }};
+    (@ mat $m:expr, $n:expr; src = $src:expr; fmt = $fmt:expr) => {
+        $crate::unwrap!($crate::ReadFrom::try_read_m_n_from($src, $m, $n, $fmt))
+    };
+    (mat $m:expr, $n:expr $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ mat $m, $n; src = src; fmt = fmt)
+    }};
+    (mat $m:expr, $n:expr $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ mat $m, $n; src = src; fmt = fmt)
+    }};
+    (@ collect $n:expr; src = $src:expr; fmt = $fmt:expr) => {
+        $crate::unwrap!($crate::ReadCollection::try_read_collection_n_from(
+            $src,
+            $n,
+            $fmt,
+            $crate::Duplicate::default(),
+        ))
+    };
+    (collect $n:expr $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ collect $n; src = src; fmt = fmt)
+    }};
+    (collect $n:expr $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ collect $n; src = src; fmt = fmt)
+    }};
+    (@ until $sentinel:expr; src = $src:expr; fmt = $fmt:expr) => {{
+        let sentinel = $sentinel;
+        $crate::unwrap!($crate::ReadFrom::try_read_while_from($src, $fmt, |t| sentinel != *t))
+    }};
+    (@ while $pred:expr; src = $src:expr; fmt = $fmt:expr) => {
+        $crate::unwrap!($crate::ReadFrom::try_read_while_from($src, $fmt, $pred))
+    };
+    (until $sentinel:expr $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ until $sentinel; src = src; fmt = fmt)
+    }};
+    (while $pred:expr $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ while $pred; src = src; fmt = fmt)
+    }};
+    (until $sentinel:expr $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ until $sentinel; src = src; fmt = fmt)
+    }};
+    (while $pred:expr $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ while $pred; src = src; fmt = fmt)
+    }};
+    (@ ..; src = $src:expr; fmt = $fmt:expr) => {
+        $crate::LenPrefixed::into_inner($crate::unwrap!($crate::ReadFrom::try_read_from($src, $fmt)))
+    };
+    (@ $dim0:expr, ..; src = $src:expr; fmt = $fmt:expr) => {{
+        let range = 0usize..$dim0;
+        ::std::vec::Vec::<_>::from_iter(range.map(|_| $crate::read!(@ ..; src = $src; fmt = $fmt)))
+    }};
+    (.. $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ ..; src = src; fmt = fmt)
+    }};
+    ($dim0:expr, .. $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
+        $crate::read!(@ $dim0, ..; src = src; fmt = fmt)
+    }};
+    (.. $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ ..; src = src; fmt = fmt)
+    }};
+    ($dim0:expr, .. $(; src = $src:expr)? ; skip = $skip:expr) => {{
+        let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
+        let fmt = &$crate::fmt::skip($skip);
+        $crate::read!(@ $dim0, ..; src = src; fmt = fmt)
+    }};
     (@ $dim0:expr $(, $dims:expr)* $(,)?; src = $src:expr; fmt = $fmt:expr) => {{
         let range = 0usize..$dim0;
         ::std::vec::Vec::<_>::from_iter(range.map(|_| $crate::read!(@ $($dims, )* ; src = $src ; fmt = $fmt)))
@@ -89,10 +212,9 @@ macro_rules! read {
         $crate::unwrap!($crate::ReadFrom::try_read_from(src, fmt))
     }};
     ($dim0:expr $(, $dims:expr)* $(,)? $(; src = $src:expr)? $(; fmt = $fmt:expr)?) => {{
-        let range = 0usize..$dim0;
         let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
         let fmt = $crate::argument_or_default!($(&$fmt)?, &$crate::fmt::Default::new());
-        ::std::vec::Vec::<_>::from_iter(range.map(|_| $crate::read!(@ $($dims, )*; src = src; fmt = fmt)))
+        $crate::read!(@ $dim0 $(, $dims)*; src = src; fmt = fmt)
     }};
     ($(,)? $(; src = $src:expr)? ; skip = $skip:expr) => {{
         let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
@@ -100,10 +222,9 @@ macro_rules! read {
         $crate::unwrap!($crate::ReadFrom::try_read_from(src, fmt))
     }};
     ($dim0:expr $(, $dims:expr)* $(,)? $(; src = $src:expr)? ; skip = $skip:expr) => {{
-        let range = 0usize..$dim0;
         let src = $crate::argument_or_default!($(&mut $src)?, &mut *$crate::stdin());
         let fmt = &$crate::fmt::skip($skip);
-        ::std::vec::Vec::<_>::from_iter(range.map(|_| $crate::read!(@ $($dims, )*; src = src; fmt = fmt)))
+        $crate::read!(@ $dim0 $(, $dims)*; src = src; fmt = fmt)
     }};
 }
 
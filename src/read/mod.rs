@@ -1,7 +1,13 @@
+pub(super) mod collection;
+pub(super) mod columns;
 pub(super) mod error;
+pub(super) mod fixed;
 pub mod fmt;
+pub(super) mod graph;
+pub(super) mod grid;
 mod impls;
-mod iter;
+pub(super) mod iter;
+pub(super) mod len_prefixed;
 mod macros;
 pub(super) mod read_from;
 pub(super) mod read_into;
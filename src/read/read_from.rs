@@ -1,5 +1,12 @@
-use super::{fmt::Format, read_one_from::ReadOneFrom};
-use crate::{array::array_try_from_fn, mat::Mat, BufReadExt, ReadError};
+use super::{fmt::Format, iter::iter_read_while, read_one_from::ReadOneFrom};
+use crate::{
+    array::array_try_from_fn,
+    ext::{CharExt, CharSet},
+    mat::Mat,
+    stream::traits::BufReadExtWithFormat,
+    BufReadExt, ReadError,
+};
+use std::mem::take;
 
 /// The error type for [ReadFrom].
 pub type ReadFromError<T> = ReadError<<T as ReadFrom>::ParseError>;
@@ -24,6 +31,83 @@ pub trait ReadFrom: Sized {
         stream: &mut S,
         format: F,
     ) -> Result<Self, ReadFromError<Self>>;
+    /// Read from `self` and parse into `Self`, or return [None] if the end of file is reached before the first token.
+    ///
+    /// The end of file after the first token is still an error,
+    /// such as the end of file in the middle of a tuple or an array.
+    #[inline]
+    fn try_read_opt_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+    ) -> Result<Option<Self>, ReadFromError<Self>> {
+        let _: usize = stream.try_skip_all(format.skip())?;
+        if stream.is_eol() {
+            return Ok(None);
+        }
+        Self::try_read_from(stream, format).map(Some)
+    }
+    /// Read from `stream` and parse into `dest` in place, which may reuse the resources of `dest`.
+    ///
+    /// On error, `dest` is left in a valid but unspecified state.
+    #[inline]
+    fn try_read_in_place_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut Self,
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        *dest = Self::try_read_from(stream, format)?;
+        Ok(())
+    }
+    /// Read `dest.len()` elements from `stream`, and parse into `dest` in place.
+    ///
+    /// On error, elements in `dest` are left in valid but unspecified states.
+    #[inline]
+    fn try_read_into_slice_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut [Self],
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        for item in dest {
+            Self::try_read_in_place_from(stream, item, format)?;
+        }
+        Ok(())
+    }
+    /// Read `n` elements from `stream`, and append them to `dest`.
+    ///
+    /// On error, elements read before are still appended.
+    #[inline]
+    fn try_read_extend_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut Vec<Self>,
+        n: usize,
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        dest.reserve(n);
+        for _ in 0..n {
+            dest.push(Self::try_read_from(stream, format)?);
+        }
+        Ok(())
+    }
+    /// Read `m * n` elements from `stream` into `dest`, which is reshaped to `m` rows and `n` columns.
+    ///
+    /// Existing storage and elements are reused.
+    /// On error, `dest` is left in a valid but unspecified state.
+    #[inline]
+    fn try_read_into_mat_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut Mat<Self>,
+        m: usize,
+        n: usize,
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        let mut data = take(dest).into_vec();
+        data.truncate(m * n);
+        let len = data.len();
+        Self::try_read_into_slice_from(stream, &mut data, format)?;
+        Self::try_read_extend_from(stream, &mut data, m * n - len, format)?;
+        *dest = Mat::from_vec(m, n, data);
+        Ok(())
+    }
     /// Read `n` elements from `self`, parse into `Self` and aggregate them into a single [Vec].
     #[inline]
     fn try_read_n_from<F: Format, S: BufReadExt>(
@@ -45,11 +129,19 @@ pub trait ReadFrom: Sized {
         n: usize,
         format: F,
     ) -> Result<Mat<Self>, ReadFromError<Self>> {
-        let mut res = Mat::with_capacity(m);
-        for _ in 0..m {
-            res.push(Self::try_read_n_from(stream, n, format)?);
-        }
-        Ok(res)
+        let data = Self::try_read_n_from(stream, m * n, format)?;
+        Ok(Mat::from_vec(m, n, data))
+    }
+    /// Read elements from `self` while they satisfy `pred`, parse into `Self` and aggregate them into a single [Vec].
+    ///
+    /// The first element that does not satisfy `pred` is consumed but not returned.
+    #[inline]
+    fn try_read_while_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+        pred: impl FnMut(&Self) -> bool,
+    ) -> Result<Vec<Self>, ReadFromError<Self>> {
+        iter_read_while(|| Self::try_read_from(stream, format), pred).collect()
     }
 }
 
@@ -63,6 +155,15 @@ impl<T: ReadOneFrom> ReadFrom for T {
     ) -> Result<T, ReadFromError<Self>> {
         Self::try_read_one_from(stream, format)
     }
+
+    #[inline]
+    fn try_read_in_place_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut Self,
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        dest.try_read_one_in_place_from(stream, format)
+    }
 }
 
 impl<T: ReadFrom, const N: usize> ReadFrom for [T; N] {
@@ -75,6 +176,15 @@ impl<T: ReadFrom, const N: usize> ReadFrom for [T; N] {
     ) -> Result<Self, ReadFromError<Self>> {
         array_try_from_fn(|| T::try_read_from(stream, format))
     }
+
+    #[inline]
+    fn try_read_in_place_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        dest: &mut Self,
+        format: F,
+    ) -> Result<(), ReadFromError<Self>> {
+        T::try_read_into_slice_from(stream, dest, format)
+    }
 }
 
 impl<T: ReadFrom, const N: usize> ReadFrom for Box<[T; N]> {
@@ -93,6 +203,42 @@ impl<T: ReadFrom, const N: usize> ReadFrom for Box<[T; N]> {
     }
 }
 
+/// Read a data item if there are more tokens in current line, otherwise return [None].
+///
+/// Such as the optional `3` in:
+///
+/// ```txt
+/// 1 2 3
+/// 1 2
+/// ```
+///
+/// Note that current line is the unconsumed part of the line buffer.
+/// If it is entirely consumed, such as before anything is read, the next line is read first,
+/// and [None] is returned at the end of input.
+impl<T: ReadFrom> ReadFrom for Option<T> {
+    type ParseError = <T as ReadFrom>::ParseError;
+
+    #[inline]
+    fn try_read_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+    ) -> Result<Option<T>, ReadFromError<Self>> {
+        if stream.get_cur_line().is_empty() && !stream.read_buf()? {
+            return Ok(None);
+        }
+        let line = stream.get_cur_line();
+        let cursor = format
+            .skip()
+            .find_first_not_matching(line)
+            .unwrap_or(line.len());
+        let rest = &line[cursor..];
+        if char::EOL.find_first_not_matching(rest).is_none() {
+            return Ok(None);
+        }
+        T::try_read_from(stream, format).map(Some)
+    }
+}
+
 /// Read several data items in a line from input stream.
 ///
 /// Such as:
@@ -1,5 +1,13 @@
-use super::{fmt::Default, read_from::ReadFromError};
-use crate::{unwrap, BufReadExt, Mat, ReadFrom};
+use super::{
+    fmt::Default,
+    iter::{iter_read_blocks, iter_read_while},
+    read_from::ReadFromError,
+};
+use crate::{
+    stream::block_buf::get_block, unwrap, BufReadExt, FixedWidth, GridCell, Mat, ReadColumns,
+    ReadFixed, ReadFrom,
+};
+use std::mem::take;
 
 /// The opposite of [ReadFrom].
 pub trait ReadInto<T>: BufReadExt {
@@ -14,12 +22,154 @@ pub trait ReadInto<T>: BufReadExt {
     /// Read from `self` and parse into `Self`.
     fn try_read(&mut self) -> Result<T, Self::Error>;
 
+    /// Read from `self` and parse into `Self`, or return [None] if the end of file is reached before the first token.
+    ///
+    /// See [ReadFrom::try_read_opt_from].
+    fn try_read_opt(&mut self) -> Result<Option<T>, Self::Error>;
+
     /// Read `n` elements from `self`, parse into `Self` and aggregate them into a single [Vec].
     fn try_read_n(&mut self, n: usize) -> Result<Vec<T>, Self::Error>;
 
     /// Read `m * n` elements from `self`, parse into `Self` and aggregate them into a single [Mat].
     fn try_read_m_n(&mut self, m: usize, n: usize) -> Result<Mat<T>, Self::Error>;
 
+    /// Read `dest.len()` elements from `self`, and parse into `dest` in place.
+    ///
+    /// See [ReadFrom::try_read_into_slice_from].
+    #[inline]
+    fn try_read_into_slice(&mut self, dest: &mut [T]) -> Result<(), Self::Error> {
+        for x in dest {
+            *x = self.try_read()?;
+        }
+        Ok(())
+    }
+
+    /// Read `n` elements from `self`, and append them to `dest`.
+    ///
+    /// See [ReadFrom::try_read_extend_from].
+    #[inline]
+    fn try_read_extend(&mut self, dest: &mut Vec<T>, n: usize) -> Result<(), Self::Error> {
+        dest.reserve(n);
+        for _ in 0..n {
+            dest.push(self.try_read()?);
+        }
+        Ok(())
+    }
+
+    /// Read `m * n` elements from `self` into `dest`, reusing its elements.
+    ///
+    /// See [ReadFrom::try_read_into_mat_from].
+    #[inline]
+    fn try_read_into_mat(
+        &mut self,
+        dest: &mut Mat<T>,
+        m: usize,
+        n: usize,
+    ) -> Result<(), Self::Error> {
+        let mut data = take(dest).into_vec();
+        data.truncate(m * n);
+        let len = data.len();
+        self.try_read_into_slice(&mut data)?;
+        self.try_read_extend(&mut data, m * n - len)?;
+        *dest = Mat::from_vec(m, n, data);
+        Ok(())
+    }
+
+    /// Read `n` rows of tuples from `self`, and aggregate each element into its own [Vec].
+    ///
+    /// See [ReadColumns::try_read_columns_from].
+    fn try_read_columns(&mut self, n: usize) -> Result<T::Columns, Self::Error>
+    where
+        T: ReadColumns;
+
+    /// Read a non-empty line from `self`, and parse its fixed-width fields in `layout`.
+    ///
+    /// See [ReadFixed::try_read_fixed_from].
+    fn try_read_fixed(&mut self, layout: &FixedWidth) -> Result<T, Self::Error>
+    where
+        T: ReadFixed;
+
+    /// Read `n` lines from `self` as rows of a character grid.
+    ///
+    /// See [GridCell::try_read_grid_from].
+    fn try_read_grid(&mut self, n: usize) -> Result<Mat<T>, Self::Error>
+    where
+        T: GridCell;
+
+    /// Read `n` lines from `self` as rows of a character grid, which may have different widths.
+    ///
+    /// See [GridCell::try_read_grid_ragged_from].
+    fn try_read_grid_ragged(&mut self, n: usize) -> Result<Vec<Vec<T>>, Self::Error>
+    where
+        T: GridCell;
+
+    /// Read `n` lines from `self` as rows of a character grid, and map each cell with `f`.
+    ///
+    /// See [GridCell::try_read_grid_map_from].
+    fn try_read_grid_map<V>(
+        &mut self,
+        n: usize,
+        f: impl FnMut(T) -> V,
+    ) -> Result<Mat<V>, Self::Error>
+    where
+        T: GridCell;
+
+    /// Read elements from `self` while they satisfy `pred`, and yield them one by one.
+    ///
+    /// The first element that does not satisfy `pred` is consumed but not yielded,
+    /// and the iteration also stops after the first error.
+    #[inline]
+    fn iter_while(
+        &mut self,
+        pred: impl FnMut(&T) -> bool,
+    ) -> impl Iterator<Item = Result<T, Self::Error>> {
+        iter_read_while(|| self.try_read(), pred)
+    }
+
+    /// Read elements from `self` until `sentinel`, and yield them one by one.
+    ///
+    /// The sentinel is consumed but not yielded, and the iteration also stops after the first error.
+    #[inline]
+    fn iter_until(
+        &mut self,
+        sentinel: impl PartialEq<T>,
+    ) -> impl Iterator<Item = Result<T, Self::Error>> {
+        self.iter_while(move |t| sentinel != *t)
+    }
+
+    /// Read blocks from `self`, parse each of them into `T`, and yield them one by one.
+    ///
+    /// Blocks are got by [BufReadExt::try_get_block], and each block is parsed from a sub-stream
+    /// that reports the end of file at the end of the block. The rest of a block is discarded.
+    /// Line numbers in errors are counted in `self`, not in the block.
+    ///
+    /// The iteration stops at the end of file, and also after the first error.
+    fn iter_blocks(&mut self) -> impl Iterator<Item = Result<T, Self::Error>>;
+
+    /// Read blocks from `self`, and parse each of them into `T`.
+    ///
+    /// This unwraps each result of [ReadInto::iter_blocks].
+    #[inline]
+    fn read_blocks(&mut self) -> impl Iterator<Item = T> {
+        self.iter_blocks().map(|res| unwrap!(res))
+    }
+
+    /// Read elements from `self` while they satisfy `pred`, and aggregate them into a single [Vec].
+    ///
+    /// The first element that does not satisfy `pred` is consumed but not returned.
+    #[inline]
+    fn try_read_while(&mut self, pred: impl FnMut(&T) -> bool) -> Result<Vec<T>, Self::Error> {
+        self.iter_while(pred).collect()
+    }
+
+    /// Read elements from `self` until `sentinel`, and aggregate them into a single [Vec].
+    ///
+    /// The sentinel is consumed but not returned.
+    #[inline]
+    fn try_read_until(&mut self, sentinel: impl PartialEq<T>) -> Result<Vec<T>, Self::Error> {
+        self.iter_until(sentinel).collect()
+    }
+
     /// Unwrap the result of [ReadInto::try_read].
     #[inline]
     #[track_caller]
@@ -27,6 +177,13 @@ pub trait ReadInto<T>: BufReadExt {
         unwrap!(self.try_read())
     }
 
+    /// Unwrap the result of [ReadInto::try_read_opt].
+    #[inline]
+    #[track_caller]
+    fn read_opt(&mut self) -> Option<T> {
+        unwrap!(self.try_read_opt())
+    }
+
     /// Unwrap the result of [ReadInto::try_read_n].
     #[inline]
     #[track_caller]
@@ -40,6 +197,91 @@ pub trait ReadInto<T>: BufReadExt {
     fn read_m_n(&mut self, m: usize, n: usize) -> Mat<T> {
         unwrap!(self.try_read_m_n(m, n))
     }
+
+    /// Unwrap the result of [ReadInto::try_read_into_slice].
+    #[inline]
+    #[track_caller]
+    fn read_into_slice(&mut self, dest: &mut [T]) {
+        unwrap!(self.try_read_into_slice(dest))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_extend].
+    #[inline]
+    #[track_caller]
+    fn read_extend(&mut self, dest: &mut Vec<T>, n: usize) {
+        unwrap!(self.try_read_extend(dest, n))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_into_mat].
+    #[inline]
+    #[track_caller]
+    fn read_into_mat(&mut self, dest: &mut Mat<T>, m: usize, n: usize) {
+        unwrap!(self.try_read_into_mat(dest, m, n))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_columns].
+    #[inline]
+    #[track_caller]
+    fn read_columns(&mut self, n: usize) -> T::Columns
+    where
+        T: ReadColumns,
+    {
+        unwrap!(self.try_read_columns(n))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_fixed].
+    #[inline]
+    #[track_caller]
+    fn read_fixed(&mut self, layout: &FixedWidth) -> T
+    where
+        T: ReadFixed,
+    {
+        unwrap!(self.try_read_fixed(layout))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_grid].
+    #[inline]
+    #[track_caller]
+    fn read_grid(&mut self, n: usize) -> Mat<T>
+    where
+        T: GridCell,
+    {
+        unwrap!(self.try_read_grid(n))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_grid_ragged].
+    #[inline]
+    #[track_caller]
+    fn read_grid_ragged(&mut self, n: usize) -> Vec<Vec<T>>
+    where
+        T: GridCell,
+    {
+        unwrap!(self.try_read_grid_ragged(n))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_grid_map].
+    #[inline]
+    #[track_caller]
+    fn read_grid_map<V>(&mut self, n: usize, f: impl FnMut(T) -> V) -> Mat<V>
+    where
+        T: GridCell,
+    {
+        unwrap!(self.try_read_grid_map(n, f))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_while].
+    #[inline]
+    #[track_caller]
+    fn read_while(&mut self, pred: impl FnMut(&T) -> bool) -> Vec<T> {
+        unwrap!(self.try_read_while(pred))
+    }
+
+    /// Unwrap the result of [ReadInto::try_read_until].
+    #[inline]
+    #[track_caller]
+    fn read_until(&mut self, sentinel: impl PartialEq<T>) -> Vec<T> {
+        unwrap!(self.try_read_until(sentinel))
+    }
 }
 
 impl<T: BufReadExt, U> ReadInto<U> for T
@@ -53,6 +295,11 @@ where
         U::try_read_from(self, Default::new())
     }
 
+    #[inline]
+    fn try_read_opt(&mut self) -> Result<Option<U>, Self::Error> {
+        U::try_read_opt_from(self, Default::new())
+    }
+
     #[inline]
     fn try_read_n(&mut self, n: usize) -> Result<Vec<U>, Self::Error> {
         U::try_read_n_from(self, n, Default::new())
@@ -62,4 +309,73 @@ where
     fn try_read_m_n(&mut self, m: usize, n: usize) -> Result<Mat<U>, Self::Error> {
         U::try_read_m_n_from(self, m, n, Default::new())
     }
+
+    #[inline]
+    fn try_read_into_slice(&mut self, dest: &mut [U]) -> Result<(), Self::Error> {
+        U::try_read_into_slice_from(self, dest, Default::new())
+    }
+
+    #[inline]
+    fn try_read_extend(&mut self, dest: &mut Vec<U>, n: usize) -> Result<(), Self::Error> {
+        U::try_read_extend_from(self, dest, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_into_mat(
+        &mut self,
+        dest: &mut Mat<U>,
+        m: usize,
+        n: usize,
+    ) -> Result<(), Self::Error> {
+        U::try_read_into_mat_from(self, dest, m, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_columns(&mut self, n: usize) -> Result<U::Columns, Self::Error>
+    where
+        U: ReadColumns,
+    {
+        U::try_read_columns_from(self, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_fixed(&mut self, layout: &FixedWidth) -> Result<U, Self::Error>
+    where
+        U: ReadFixed,
+    {
+        U::try_read_fixed_from(self, layout)
+    }
+
+    #[inline]
+    fn try_read_grid(&mut self, n: usize) -> Result<Mat<U>, Self::Error>
+    where
+        U: GridCell,
+    {
+        U::try_read_grid_from(self, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_grid_ragged(&mut self, n: usize) -> Result<Vec<Vec<U>>, Self::Error>
+    where
+        U: GridCell,
+    {
+        U::try_read_grid_ragged_from(self, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_grid_map<V>(
+        &mut self,
+        n: usize,
+        f: impl FnMut(U) -> V,
+    ) -> Result<Mat<V>, Self::Error>
+    where
+        U: GridCell,
+    {
+        U::try_read_grid_map_from(self, n, Default::new(), f)
+    }
+
+    #[inline]
+    fn iter_blocks(&mut self) -> impl Iterator<Item = Result<U, Self::Error>> {
+        iter_read_blocks(|| get_block(self), Default::new())
+    }
 }
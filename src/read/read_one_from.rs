@@ -3,9 +3,9 @@ use super::{
     iter::{ReadAll, ReadAllIn},
 };
 use crate::{
-    ext::{Pattern, PatternError},
+    ext::{CharExt, CharSet, Pattern, PatternError},
     stream::{error::StreamError, ext::Any, traits::BufReadExtWithFormat},
-    BufReadExt, ReadError,
+    BufReadExt, Mat, ReadError,
 };
 use std::any::type_name;
 
@@ -33,6 +33,16 @@ pub trait ReadOneFrom: Sized {
     /// Parse a string into `Self`.
     fn parse(s: &str) -> Result<Self, ReadError<Self::ParseError>>;
 
+    /// Parse a string into `self` in place, which may reuse the resources of `self`.
+    ///
+    /// By default, this replaces `self` with the result of [ReadOneFrom::parse].
+    /// On error, `self` is left in a valid but unspecified state.
+    #[inline]
+    fn parse_into(&mut self, s: &str) -> Result<(), ReadError<Self::ParseError>> {
+        *self = Self::parse(s)?;
+        Ok(())
+    }
+
     /// Check if a character is acceptable.
     #[inline]
     fn accept() -> impl Pattern<Item = char> {
@@ -45,19 +55,25 @@ pub trait ReadOneFrom: Sized {
         stream: &mut S,
         format: F,
     ) -> Result<Self, ReadError<Self::ParseError>> {
-        let s = stream
-            .try_get_string_some(format.skip(), Self::accept())
-            .map_err(|error| match error {
-                PatternError::Extra(StreamError::IOError(e)) => ReadError::IOError(e),
-                PatternError::Extra(StreamError::Eof) => ReadError::EOF,
-                PatternError::Extra(StreamError::Eol) => ReadError::EOL,
-                PatternError::UnexpectedChar(c) => {
-                    ReadError::UnexpectedChar(c, type_name::<Self>())
-                } // PatternError::Unfulfilled(s) => ReadError::Unfulfilled(s),
-            })?;
+        let s = get_string_some::<Self, _, _>(stream, format)?;
         Self::parse(s)
     }
 
+    /// Read from `stream` and parse into `self` in place.
+    ///
+    /// By default, this replaces `self` with the result of [ReadOneFrom::try_read_one_from].
+    /// Types that reuse their resources in [ReadOneFrom::parse_into] can override this to read the token
+    /// as [ReadOneFrom::try_read_one_from] does and then call [ReadOneFrom::parse_into].
+    #[inline]
+    fn try_read_one_in_place_from<F: Format, S: BufReadExt>(
+        &mut self,
+        stream: &mut S,
+        format: F,
+    ) -> Result<(), ReadError<Self::ParseError>> {
+        *self = Self::try_read_one_from(stream, format)?;
+        Ok(())
+    }
+
     /// Read an element in a single non-whitespace character from `stream`, parse into `Self`.
     #[inline]
     fn try_read_in_char_from<F: Format, S: BufReadExt>(
@@ -117,4 +133,104 @@ pub trait ReadOneFrom: Sized {
         ReadAllIn::<F, Self>::new(stream.try_get_line_some_trimmed(format.skip())?, format)
             .collect()
     }
+
+    /// Read exactly `n` elements in a non-empty line from `stream` into a [Vec] of `Self`.
+    ///
+    /// Unlike [ReadOneFrom::try_read_some_in_line_from], [ReadError::TokenCount] is returned
+    /// if the line does not contain exactly `n` elements.
+    /// If `n` is 0, nothing is read.
+    #[inline]
+    fn try_read_line_of_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        n: usize,
+        format: F,
+    ) -> Result<Vec<Self>, ReadError<Self::ParseError>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        let line = stream.try_get_line_some_trimmed(format.skip())?;
+        let res: Vec<Self> = ReadAllIn::<F, Self>::new(line, format).collect::<Result<_, _>>()?;
+        if res.len() != n {
+            return Err(ReadError::TokenCount {
+                line: stream.line_number(),
+                expected: n,
+                found: res.len(),
+            });
+        }
+        Ok(res)
+    }
+
+    /// Read `m` non-empty lines from `stream`, each with exactly `n` elements, into a [Mat] of `Self`.
+    ///
+    /// See [ReadOneFrom::try_read_line_of_from].
+    #[inline]
+    fn try_read_rows_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        m: usize,
+        n: usize,
+        format: F,
+    ) -> Result<Mat<Self>, ReadError<Self::ParseError>> {
+        let mut data = Vec::with_capacity(m * n);
+        for _ in 0..m {
+            data.extend(Self::try_read_line_of_from(stream, n, format)?);
+        }
+        Ok(Mat::from_vec(m, n, data))
+    }
+
+    /// Read a matrix of unknown shape from `stream`, until end of file or a blank line.
+    ///
+    /// Each line is a row, read with [ReadOneFrom::try_read_some_in_line_from],
+    /// and the number of columns is inferred from the first row.
+    /// Blank lines before the first row are skipped, and the blank line after the last row is consumed.
+    ///
+    /// Returns [ReadError::RaggedRow] if a row has a different number of elements from the first row.
+    #[inline]
+    fn try_read_mat_auto_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        format: F,
+    ) -> Result<Mat<Self>, ReadError<Self::ParseError>> {
+        let _: usize = stream.try_skip_all(format.skip())?;
+        let mut data = Vec::new();
+        let (mut rows, mut cols) = (0, 0);
+        while !stream.is_eol() {
+            let row = Self::try_read_some_in_line_from(stream, format)?;
+            if rows == 0 {
+                cols = row.len();
+            } else if row.len() != cols {
+                return Err(ReadError::RaggedRow {
+                    line: stream.line_number(),
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend(row);
+            rows += 1;
+            if !stream.read_buf()? {
+                break;
+            }
+            let line = format.skip().trim(stream.get_cur_line());
+            if char::EOL.find_first_not_matching(line).is_none() {
+                let _: &str = stream.try_get_line()?;
+                break;
+            }
+        }
+        Ok(Mat::from_vec(rows, cols, data))
+    }
+}
+
+/// Get a single string that is accepted by `T` from `stream`.
+#[inline]
+pub(super) fn get_string_some<T: ReadOneFrom, F: Format, S: BufReadExt>(
+    stream: &mut S,
+    format: F,
+) -> Result<&str, ReadError<T::ParseError>> {
+    stream
+        .try_get_string_some(format.skip(), T::accept())
+        .map_err(|error| match error {
+            PatternError::Extra(StreamError::IOError(e)) => ReadError::IOError(e),
+            PatternError::Extra(StreamError::Eof) => ReadError::EOF,
+            PatternError::Extra(StreamError::Eol) => ReadError::EOL,
+            PatternError::UnexpectedChar(c) => ReadError::UnexpectedChar(c, type_name::<T>()),
+            // PatternError::Unfulfilled(s) => ReadError::Unfulfilled(s),
+        })
 }
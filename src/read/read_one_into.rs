@@ -1,5 +1,5 @@
 use super::fmt::Default;
-use crate::{stream, unwrap, BufReadExt, ReadOneFrom, ReadOneFromError};
+use crate::{stream, unwrap, BufReadExt, Mat, ReadOneFrom, ReadOneFromError};
 
 /// The opposite of [ReadOneFrom].
 pub trait ReadOneInto<T> {
@@ -31,6 +31,28 @@ pub trait ReadOneInto<T> {
     /// Read all elements in a non-empty line from `self`.
     fn try_read_some_in_line(&mut self) -> Result<Vec<T>, Self::Error>;
 
+    /// Read exactly `n` elements in a non-empty line from `self`.
+    ///
+    /// See [ReadOneFrom::try_read_line_of_from].
+    fn try_read_line_of(&mut self, n: usize) -> Result<Vec<T>, Self::Error>;
+
+    /// Read `m` non-empty lines from `self`, each with exactly `n` elements.
+    ///
+    /// See [ReadOneFrom::try_read_rows_from].
+    #[inline]
+    fn try_read_rows(&mut self, m: usize, n: usize) -> Result<Mat<T>, Self::Error> {
+        let mut data = Vec::with_capacity(m * n);
+        for _ in 0..m {
+            data.extend(self.try_read_line_of(n)?);
+        }
+        Ok(Mat::from_vec(m, n, data))
+    }
+
+    /// Read a matrix of unknown shape from `self`, until end of file or a blank line.
+    ///
+    /// See [ReadOneFrom::try_read_mat_auto_from].
+    fn try_read_mat_auto(&mut self) -> Result<Mat<T>, Self::Error>;
+
     /// Unwrap the result of [ReadOneInto::try_read_one].
     #[inline]
     #[track_caller]
@@ -79,6 +101,27 @@ pub trait ReadOneInto<T> {
     fn read_some_in_line(&mut self) -> Vec<T> {
         unwrap!(self.try_read_some_in_line())
     }
+
+    /// Unwrap the result of [ReadOneInto::try_read_line_of].
+    #[inline]
+    #[track_caller]
+    fn read_line_of(&mut self, n: usize) -> Vec<T> {
+        unwrap!(self.try_read_line_of(n))
+    }
+
+    /// Unwrap the result of [ReadOneInto::try_read_rows].
+    #[inline]
+    #[track_caller]
+    fn read_rows(&mut self, m: usize, n: usize) -> Mat<T> {
+        unwrap!(self.try_read_rows(m, n))
+    }
+
+    /// Unwrap the result of [ReadOneInto::try_read_mat_auto].
+    #[inline]
+    #[track_caller]
+    fn read_mat_auto(&mut self) -> Mat<T> {
+        unwrap!(self.try_read_mat_auto())
+    }
 }
 
 impl<T: ReadOneFrom, U: BufReadExt> ReadOneInto<T> for U {
@@ -118,4 +161,19 @@ impl<T: ReadOneFrom, U: BufReadExt> ReadOneInto<T> for U {
     fn try_read_some_in_line(&mut self) -> Result<Vec<T>, Self::Error> {
         T::try_read_some_in_line_from(self, Default::new())
     }
+
+    #[inline]
+    fn try_read_line_of(&mut self, n: usize) -> Result<Vec<T>, Self::Error> {
+        T::try_read_line_of_from(self, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_rows(&mut self, m: usize, n: usize) -> Result<Mat<T>, Self::Error> {
+        T::try_read_rows_from(self, m, n, Default::new())
+    }
+
+    #[inline]
+    fn try_read_mat_auto(&mut self) -> Result<Mat<T>, Self::Error> {
+        T::try_read_mat_auto_from(self, Default::new())
+    }
 }
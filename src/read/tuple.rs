@@ -1,4 +1,8 @@
-use crate::{fmt::Format, BufReadExt, ReadFrom, ReadFromError};
+use super::{
+    columns::{Columns, ReadColumns},
+    fixed::{next_field, FixedWidth, ReadFixed},
+};
+use crate::{fmt::Format, BufReadExt, ReadFrom, ReadFromError, ReadOneFrom};
 use std::fmt::{self, Display};
 
 macro_rules! impl_read_into_for_tuple {
@@ -28,14 +32,29 @@ macro_rules! impl_read_into_for_tuple {
             type ParseError = $e<$(<$t as ReadFrom>::ParseError, )+>;
             #[inline]
             fn try_read_from<F: Format, S: BufReadExt>(stream: &mut S, format: F) -> Result<($($t, )+), ReadFromError<Self>> {
-                Ok(( $(<$t as ReadFrom>::try_read_from(stream, format).map_err(|err| match err {
-                    ReadFromError::<$t>::IOError(e) => ReadFromError::<Self>::IOError(e),
-                    ReadFromError::<$t>::EOF => ReadFromError::<Self>::EOF,
-                    ReadFromError::<$t>::EOL => ReadFromError::<Self>::EOL,
-                    ReadFromError::<$t>::UnexpectedChar(c, s) => ReadFromError::<Self>::UnexpectedChar(c, s),
-                    // ReadFromError::<$t>::Unfulfilled(s) => ReadFromError::<Self>::Unfulfilled(s),
-                    ReadFromError::<$t>::FromStrError(e, s, n) => ReadFromError::<Self>::FromStrError($e::$t(e), s, n),
-                })?, )+ ))
+                Ok(( $(<$t as ReadFrom>::try_read_from(stream, format).map_err(|err| err.map_parse_error($e::$t))?, )+ ))
+            }
+        }
+        impl<$($t: ReadFrom, )+> ReadColumns for ( $($t, )+ ) {
+            type Columns = ( $(Vec<$t>, )+ );
+            #[inline]
+            #[expect(non_snake_case, reason = "Columns are named after their element types.")]
+            fn try_read_columns_from<F: Format, S: BufReadExt>(stream: &mut S, n: usize, format: F) -> Result<( $(Vec<$t>, )+ ), ReadFromError<Self>> {
+                $( let mut $t = Vec::with_capacity(n); )+
+                for _ in 0..n {
+                    $( $t.push(<$t as ReadFrom>::try_read_from(stream, format).map_err(|err| err.map_parse_error($e::$t))?); )+
+                }
+                Ok(( $($t, )+ ))
+            }
+        }
+        impl<$($t: ReadFrom, )+> Columns for ( $(Vec<$t>, )+ ) {
+            type Row = ( $($t, )+ );
+        }
+        impl<$($t: ReadOneFrom, )+> ReadFixed for ( $($t, )+ ) {
+            #[inline]
+            fn try_parse_fixed(line: &str, layout: &FixedWidth) -> Result<( $($t, )+ ), ReadFromError<Self>> {
+                let mut fields = layout.split(line);
+                Ok(( $( next_field::<$t>(&mut fields, line).and_then(<$t as ReadOneFrom>::parse).map_err(|err| err.map_parse_error($e::$t))?, )+ ))
             }
         }
     };
@@ -6,34 +6,209 @@
 //! recommended to use this module in a multi-threaded environment.
 use crate::InputStream;
 use std::{
-    io::{self, BufReader, Stdin},
+    cell::Cell,
+    fmt,
+    io::{self, BufRead, BufReader, Write},
+    mem,
+    ops::{Deref, DerefMut},
     sync::{LazyLock, Mutex, MutexGuard},
 };
 
 pub(crate) mod read_into;
 pub(crate) mod stream;
 
+/// Stream behind [stdin].
+///
+/// It reads from the standard input, unless the standard input is redirected in current thread,
+/// such as by [stress](crate::stress()).
+pub type StdinStream = InputStream<Box<dyn BufRead + Send>>;
+
 /// Standard input stream.
-pub(crate) static STDIN: LazyLock<Mutex<InputStream<BufReader<Stdin>>>> =
-    LazyLock::new(|| Mutex::new(InputStream::new(BufReader::new(io::stdin()))));
+pub(crate) static STDIN: LazyLock<Mutex<StdinStream>> =
+    LazyLock::new(|| Mutex::new(InputStream::new(Box::new(BufReader::new(io::stdin())))));
+
+/// Redirection of a standard stream in current thread.
+enum Redirect<T> {
+    /// Not redirected.
+    Off,
+    /// Redirected to the given stream.
+    On(T),
+    /// Redirected, and the stream is held by a guard.
+    Locked,
+}
+
+thread_local! {
+    static STDIN_REDIRECT: Cell<Redirect<StdinStream>> = const { Cell::new(Redirect::Off) };
+    static STDOUT_REDIRECT: Cell<Redirect<Vec<u8>>> = const { Cell::new(Redirect::Off) };
+}
+
+/// Exclusive handle to the standard input stream, returned by [stdin].
+pub struct StdinGuard(StdinGuardInner);
+
+enum StdinGuardInner {
+    Std(MutexGuard<'static, StdinStream>),
+    Redirected(StdinStream),
+}
+
+impl Deref for StdinGuard {
+    type Target = StdinStream;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match &self.0 {
+            StdinGuardInner::Std(stream) => stream,
+            StdinGuardInner::Redirected(stream) => stream,
+        }
+    }
+}
+
+impl DerefMut for StdinGuard {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match &mut self.0 {
+            StdinGuardInner::Std(stream) => stream,
+            StdinGuardInner::Redirected(stream) => stream,
+        }
+    }
+}
+
+impl Drop for StdinGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if let StdinGuardInner::Redirected(stream) = &mut self.0 {
+            let stream = mem::replace(stream, InputStream::new(Box::new(io::empty())));
+            STDIN_REDIRECT.set(Redirect::On(stream));
+        }
+    }
+}
+
+impl fmt::Debug for StdinGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdinGuard").finish_non_exhaustive()
+    }
+}
+
+/// Exclusive handle to the standard output stream, returned by [stdout].
+pub struct StdoutGuard(StdoutGuardInner);
+
+enum StdoutGuardInner {
+    Std(io::StdoutLock<'static>),
+    Redirected(Vec<u8>),
+}
+
+impl Write for StdoutGuard {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.0 {
+            StdoutGuardInner::Std(lock) => lock.write(buf),
+            StdoutGuardInner::Redirected(out) => out.write(buf),
+        }
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match &mut self.0 {
+            StdoutGuardInner::Std(lock) => lock.write_all(buf),
+            StdoutGuardInner::Redirected(out) => out.write_all(buf),
+        }
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.0 {
+            StdoutGuardInner::Std(lock) => lock.flush(),
+            StdoutGuardInner::Redirected(out) => out.flush(),
+        }
+    }
+}
+
+impl Drop for StdoutGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if let StdoutGuardInner::Redirected(buf) = &mut self.0 {
+            STDOUT_REDIRECT.set(Redirect::On(mem::take(buf)));
+        }
+    }
+}
+
+impl fmt::Debug for StdoutGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdoutGuard").finish_non_exhaustive()
+    }
+}
 
 /// Get an exclusive handle to the standard input stream.
 ///
 /// See [io::stdin] and [io::Stdin::lock] for more information.
 ///
+/// If the standard input is redirected in current thread, such as by [stress](crate::stress()),
+/// the handle reads from the redirected input instead.
+///
 /// # Panics
 ///
 /// This function will panic if the standard input stream is already locked by current thread,
 /// or if the standard input stream is poisoned.
 #[inline]
-pub fn stdin() -> MutexGuard<'static, InputStream<BufReader<Stdin>>> {
-    STDIN.lock().unwrap()
+#[track_caller]
+pub fn stdin() -> StdinGuard {
+    match STDIN_REDIRECT.replace(Redirect::Locked) {
+        Redirect::Off => {
+            STDIN_REDIRECT.set(Redirect::Off);
+            StdinGuard(StdinGuardInner::Std(STDIN.lock().unwrap()))
+        }
+        Redirect::On(stream) => StdinGuard(StdinGuardInner::Redirected(stream)),
+        Redirect::Locked => panic!("redirected standard input is already locked by current thread"),
+    }
 }
 
 /// Get an exclusive handle to the standard output stream.
 ///
 /// See [io::stdout] and [io::Stdout::lock] for more information.
+///
+/// If the standard output is redirected in current thread, such as by [stress](crate::stress()),
+/// the handle writes into the redirected buffer instead.
+///
+/// # Panics
+///
+/// This function will panic if the redirected standard output is already locked by current thread.
 #[inline]
-pub fn stdout() -> io::StdoutLock<'static> {
-    io::stdout().lock()
+#[track_caller]
+pub fn stdout() -> StdoutGuard {
+    match STDOUT_REDIRECT.replace(Redirect::Locked) {
+        Redirect::Off => {
+            STDOUT_REDIRECT.set(Redirect::Off);
+            StdoutGuard(StdoutGuardInner::Std(io::stdout().lock()))
+        }
+        Redirect::On(buf) => StdoutGuard(StdoutGuardInner::Redirected(buf)),
+        Redirect::Locked => {
+            panic!("redirected standard output is already locked by current thread")
+        }
+    }
+}
+
+/// Restore the previous redirections of current thread when dropped, even during unwinding.
+struct Restore(Redirect<StdinStream>, Redirect<Vec<u8>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        STDIN_REDIRECT.set(mem::replace(&mut self.0, Redirect::Off));
+        STDOUT_REDIRECT.set(mem::replace(&mut self.1, Redirect::Off));
+    }
+}
+
+/// Call `f` with [stdin] reading from `input` and [stdout] writing into a buffer in current thread,
+/// and return the result of `f` and the buffer.
+pub(crate) fn redirect<R>(
+    input: impl BufRead + Send + 'static,
+    f: impl FnOnce() -> R,
+) -> (R, Vec<u8>) {
+    let restore = Restore(
+        STDIN_REDIRECT.replace(Redirect::On(InputStream::new(Box::new(input)))),
+        STDOUT_REDIRECT.replace(Redirect::On(Vec::new())),
+    );
+    let result = f();
+    let output = match STDOUT_REDIRECT.replace(Redirect::Off) {
+        Redirect::On(buf) => buf,
+        Redirect::Off | Redirect::Locked => Vec::new(),
+    };
+    drop(restore);
+    (result, output)
 }
@@ -1,5 +1,11 @@
 use crate::{
-    stdin, Mat, ReadFrom, ReadFromError, ReadInto, ReadOneFrom, ReadOneFromError, ReadOneInto,
+    fmt::Default,
+    read::iter::{iter_read_blocks, iter_read_while},
+    stdin,
+    stream::block_buf::get_block,
+    Csr, EdgeError, FixedWidth, GraphLayout, GridCell, Mat, ReadColumns, ReadFixed, ReadFrom,
+    ReadFromError, ReadGraph, ReadInto, ReadOneFrom, ReadOneFromError, ReadOneInto,
+    WeightedEdgeError,
 };
 pub use read_m_n as read_mat;
 pub use read_n as read_vec;
@@ -12,21 +18,21 @@ macro_rules! expose_stdin {
     ($try_fn:ident $fn:ident $trait_into:ident [$ty_arg:ident] [$trait:ident] [$($trait_arg:tt)*] ($($arg:ident: $arg_ty:ty), *) -> $ret:ty | $err:ty) => {
         #[doc = concat!("Call [`", stringify!($trait_into), "::", stringify!($try_fn), "`] on [stdin].")]
         ///
+        /// If this function is called in multiple threads, the behavior is undefined, possibly causing a deadlock.
+        ///
         /// # Panics
         ///
         #[doc = concat!("If [`", stringify!($trait_into), "::", stringify!($try_fn), "`] panics.")]
         ///
         /// # Errors
         ///
-        /// If this function is called in multiple threads, the behavior is undefined, possibly causing a deadlock.
-        ///
         #[doc = concat!("If [`", stringify!($trait_into), "::", stringify!($try_fn), "`] returns an error.")]
         #[inline]
         pub fn $try_fn<$ty_arg>($($arg: $arg_ty),*) -> Result<$ret, $err>
         where
             $ty_arg: $trait,
         {
-            stdin().$try_fn($($arg),*)
+            $trait_into::<$($trait_arg)*>::$try_fn(&mut *stdin(), $($arg),*)
         }
 
         #[doc = concat!("Unwrap the result of [`", stringify!($try_fn), "`].")]
@@ -39,12 +45,40 @@ macro_rules! expose_stdin {
             $crate::unwrap!($try_fn::<$ty_arg>($($arg),*))
         }
     };
+    ($try_fn:ident $fn:ident $trait_into:ident $(<$ty_arg:ident: $first:ident $(+ $bound:ident)*>)? ($($arg:ident: $arg_ty:ty), *) -> $ret:ty | $err:ty) => {
+        #[doc = concat!("Call [`", stringify!($trait_into), "::", stringify!($try_fn), "`] on [stdin].")]
+        ///
+        /// If this function is called in multiple threads, the behavior is undefined, possibly causing a deadlock.
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("If [`", stringify!($trait_into), "::", stringify!($try_fn), "`] panics.")]
+        ///
+        /// # Errors
+        ///
+        #[doc = concat!("If [`", stringify!($trait_into), "::", stringify!($try_fn), "`] returns an error.")]
+        #[inline]
+        pub fn $try_fn$(<$ty_arg: $first $(+ $bound)*>)?($($arg: $arg_ty),*) -> Result<$ret, $err> {
+            $trait_into::$try_fn(&mut *stdin(), $($arg),*)
+        }
+
+        #[doc = concat!("Unwrap the result of [`", stringify!($try_fn), "`].")]
+        #[track_caller]
+        #[inline]
+        pub fn $fn$(<$ty_arg: $first $(+ $bound)*>)?($($arg: $arg_ty),*) -> $ret {
+            $crate::unwrap!($try_fn($($arg),*))
+        }
+    };
 }
 
 expose_stdin!(
     try_read read ReadInto
     [T] [ReadFrom] [T] () -> T | ReadFromError<T>
 );
+expose_stdin!(
+    try_read_opt read_opt ReadInto
+    [T] [ReadFrom] [T] () -> Option<T> | ReadFromError<T>
+);
 expose_stdin!(
     try_read_n read_n ReadInto
     [T] [ReadFrom] [T] (n: usize) -> Vec<T> | ReadFromError<T>
@@ -53,6 +87,142 @@ expose_stdin!(
     try_read_m_n read_m_n ReadInto
     [T] [ReadFrom] [T] (m: usize, n: usize) -> Mat<T> | ReadFromError<T>
 );
+expose_stdin!(
+    try_read_into_slice read_into_slice ReadInto
+    [T] [ReadFrom] [T] (dest: &mut [T]) -> () | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_extend read_extend ReadInto
+    [T] [ReadFrom] [T] (dest: &mut Vec<T>, n: usize) -> () | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_into_mat read_into_mat ReadInto
+    [T] [ReadFrom] [T] (dest: &mut Mat<T>, m: usize, n: usize) -> () | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_columns read_columns ReadInto
+    [T] [ReadColumns] [T] (n: usize) -> T::Columns | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_fixed read_fixed ReadInto
+    [T] [ReadFixed] [T] (layout: &FixedWidth) -> T | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_grid read_grid ReadInto
+    [T] [GridCell] [T] (n: usize) -> Mat<T> | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_grid_ragged read_grid_ragged ReadInto
+    [T] [GridCell] [T] (n: usize) -> Vec<Vec<T>> | ReadFromError<T>
+);
+
+/// Call [`ReadInto::try_read_grid_map`] on [stdin].
+///
+/// If this function is called in multiple threads, the behavior is undefined, possibly causing a deadlock.
+///
+/// # Panics
+///
+/// If [`ReadInto::try_read_grid_map`] panics.
+///
+/// # Errors
+///
+/// If [`ReadInto::try_read_grid_map`] returns an error.
+#[inline]
+pub fn try_read_grid_map<T: GridCell, V>(
+    n: usize,
+    f: impl FnMut(T) -> V,
+) -> Result<Mat<V>, ReadFromError<T>> {
+    ReadInto::<T>::try_read_grid_map(&mut *stdin(), n, f)
+}
+
+/// Unwrap the result of [`try_read_grid_map`].
+#[track_caller]
+#[inline]
+pub fn read_grid_map<T: GridCell, V>(n: usize, f: impl FnMut(T) -> V) -> Mat<V> {
+    crate::unwrap!(try_read_grid_map(n, f))
+}
+
+expose_stdin!(
+    try_read_graph read_graph ReadGraph
+    (n: usize, m: usize, layout: GraphLayout) -> Vec<Vec<usize>> | EdgeError
+);
+expose_stdin!(
+    try_read_weighted_graph read_weighted_graph ReadGraph <W: ReadFrom + Clone>
+    (n: usize, m: usize, layout: GraphLayout) -> Vec<Vec<(usize, W)>> | WeightedEdgeError<W>
+);
+expose_stdin!(
+    try_read_csr_graph read_csr_graph ReadGraph
+    (n: usize, m: usize, layout: GraphLayout) -> Csr<usize> | EdgeError
+);
+expose_stdin!(
+    try_read_weighted_csr_graph read_weighted_csr_graph ReadGraph <W: ReadFrom + Clone>
+    (n: usize, m: usize, layout: GraphLayout) -> Csr<(usize, W)> | WeightedEdgeError<W>
+);
+expose_stdin!(
+    try_read_tree_parents read_tree_parents ReadGraph
+    (n: usize, layout: GraphLayout) -> Vec<Vec<usize>> | ReadFromError<usize>
+);
+
+expose_stdin!(
+    try_read_while read_while ReadInto
+    [T] [ReadFrom] [T] (pred: impl FnMut(&T) -> bool) -> Vec<T> | ReadFromError<T>
+);
+expose_stdin!(
+    try_read_until read_until ReadInto
+    [T] [ReadFrom] [T] (sentinel: impl PartialEq<T>) -> Vec<T> | ReadFromError<T>
+);
+
+/// Call [`ReadInto::iter_while`] on [stdin].
+///
+/// [stdin] is locked only during reading each element, so it can be used between iterations.
+///
+/// # Panics
+///
+/// If [stdin] panics.
+#[inline]
+pub fn iter_while<T: ReadFrom>(
+    pred: impl FnMut(&T) -> bool,
+) -> impl Iterator<Item = Result<T, ReadFromError<T>>> {
+    iter_read_while(|| stdin().try_read(), pred)
+}
+
+/// Call [`ReadInto::iter_until`] on [stdin].
+///
+/// [stdin] is locked only during reading each element, so it can be used between iterations.
+///
+/// # Panics
+///
+/// If [stdin] panics.
+#[inline]
+pub fn iter_until<T: ReadFrom>(
+    sentinel: impl PartialEq<T>,
+) -> impl Iterator<Item = Result<T, ReadFromError<T>>> {
+    iter_while(move |t| sentinel != *t)
+}
+
+/// Call [`ReadInto::iter_blocks`] on [stdin].
+///
+/// [stdin] is locked only during reading each block, so it can be used between iterations.
+///
+/// # Panics
+///
+/// If [stdin] panics.
+#[inline]
+pub fn iter_blocks<T: ReadFrom>() -> impl Iterator<Item = Result<T, ReadFromError<T>>> {
+    iter_read_blocks(|| get_block(&mut *stdin()), Default::new())
+}
+
+/// Call [`ReadInto::read_blocks`] on [stdin].
+///
+/// [stdin] is locked only during reading each block, so it can be used between iterations.
+///
+/// # Panics
+///
+/// If [stdin] panics, or if [`iter_blocks`] yields an error.
+#[inline]
+pub fn read_blocks<T: ReadFrom>() -> impl Iterator<Item = T> {
+    iter_blocks().map(|res| crate::unwrap!(res))
+}
 
 expose_stdin!(
     try_read_one read_one ReadOneInto
@@ -78,6 +248,18 @@ expose_stdin!(
     try_read_some_in_line read_some_in_line ReadOneInto
     [T] [ReadOneFrom] [T] () -> Vec<T> | ReadOneFromError<T>
 );
+expose_stdin!(
+    try_read_line_of read_line_of ReadOneInto
+    [T] [ReadOneFrom] [T] (n: usize) -> Vec<T> | ReadOneFromError<T>
+);
+expose_stdin!(
+    try_read_rows read_rows ReadOneInto
+    [T] [ReadOneFrom] [T] (m: usize, n: usize) -> Mat<T> | ReadOneFromError<T>
+);
+expose_stdin!(
+    try_read_mat_auto read_mat_auto ReadOneInto
+    [T] [ReadOneFrom] [T] () -> Mat<T> | ReadOneFromError<T>
+);
 expose_stdin!(
     try_read_in_char read_in_char ReadOneInto
     [T] [ReadOneFrom] [T] () -> T | ReadOneFromError<T>
@@ -0,0 +1,128 @@
+use super::{
+    as_slice_from, err_eof,
+    error::StreamError,
+    ext::{CharExt, CharSet, StrExt},
+    traits::BufReadExt,
+};
+
+/// A stream over a block of lines, which reports end of file at the end of the block.
+pub(crate) struct BlockBuf<'a> {
+    buf: &'a str,
+    cursor: usize,
+    line_end: usize,
+    line_number: usize,
+}
+
+impl<'a> BlockBuf<'a> {
+    /// Create a stream over `buf`, whose first line is numbered `first_line`.
+    #[inline]
+    pub(crate) const fn new(buf: &'a str, first_line: usize) -> Self {
+        let cursor = 0;
+        let line_end = 0;
+        let line_number = first_line.saturating_sub(1);
+        Self {
+            buf,
+            cursor,
+            line_end,
+            line_number,
+        }
+    }
+}
+
+impl BufReadExt<char> for BlockBuf<'_> {
+    #[inline]
+    fn get_cur_line(&self) -> &str {
+        let line = as_slice_from(self.buf, self.cursor);
+        let line = unsafe { line.get_unchecked(..self.line_end - self.cursor) };
+        line
+    }
+    #[inline]
+    fn line_number(&self) -> usize {
+        self.line_number
+    }
+    #[inline]
+    unsafe fn skip(&mut self, n: usize) {
+        self.cursor += n;
+        debug_assert!(self.cursor <= self.line_end);
+        debug_assert!(self.buf.is_char_boundary(self.cursor));
+    }
+    #[inline]
+    fn read_buf(&mut self) -> Result<bool, StreamError> {
+        self.cursor = self.line_end;
+        if self.line_end < self.buf.len() {
+            let rest = as_slice_from(self.buf, self.line_end);
+            self.line_end += rest.find('\n').map_or(rest.len(), |i| i + 1);
+            self.line_number += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+    #[inline]
+    fn fill_buf(&mut self) -> Result<(), StreamError> {
+        if self.read_buf()? {
+            Ok(())
+        } else {
+            Err(err_eof())
+        }
+    }
+}
+
+/// Get the next block from `stream` as [BufReadExt::try_get_block] does,
+/// along with the number of its first line in `stream`.
+pub(crate) fn get_block<Char, S>(stream: &mut S) -> Result<(usize, String), StreamError>
+where
+    S: BufReadExt<Char> + ?Sized,
+    Char: CharExt + Into<char> + Copy,
+    for<'a> &'a [Char]: CharSet<Item = Char>,
+    for<'a> &'a str: StrExt<'a, Char>,
+{
+    let mut block = String::new();
+    loop {
+        let line = stream.try_get_line()?;
+        if !line.trim().is_empty() {
+            block.push_str(line);
+            block.push('\n');
+            break;
+        }
+        stream.fill_buf()?;
+    }
+    let first_line = stream.line_number();
+    while stream.read_buf()? {
+        let line = stream.try_get_line()?;
+        if line.trim().is_empty() {
+            break;
+        }
+        block.push_str(line);
+        block.push('\n');
+    }
+    Ok((first_line, block))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fmt::{Default, Format},
+        stream::{block_buf::BlockBuf, error::StreamError, traits::BufReadExtWithFormat as _},
+        BufReadExt,
+    };
+
+    #[test]
+    fn lines() {
+        let mut stream = BlockBuf::new("1 2\n3\n", 1);
+        assert_eq!(stream.try_get_line().unwrap(), "1 2");
+        assert_eq!(stream.try_get_line().unwrap(), "3");
+        assert!(matches!(stream.try_get_line(), Err(StreamError::Eof)));
+    }
+
+    #[test]
+    fn skip_all() {
+        let mut stream = BlockBuf::new("1\n\n2", 1);
+        let d: Default<char> = Default::new();
+        assert_eq!(stream.try_get_non_skipped(d.skip()).unwrap(), '1');
+        assert_eq!(stream.try_get_non_skipped(d.skip()).unwrap(), '2');
+        assert_eq!(stream.try_skip_all(d.skip()).unwrap(), 0);
+        assert!(stream.is_eol());
+        assert!(matches!(stream.try_get(), Err(StreamError::Eof)));
+    }
+}
@@ -10,6 +10,7 @@ pub struct InputStream<B> {
     buffer: B,
     line_buf: String,
     cursor: usize,
+    line_number: usize,
 }
 
 impl<B: BufRead> InputStream<B> {
@@ -18,10 +19,12 @@ impl<B: BufRead> InputStream<B> {
     pub const fn new(buffer: B) -> Self {
         let line_buf = String::new();
         let cursor = 0;
+        let line_number = 0;
         Self {
             buffer,
             line_buf,
             cursor,
+            line_number,
         }
     }
 }
@@ -32,6 +35,10 @@ impl<B: BufRead> BufReadExt<char> for InputStream<B> {
         as_slice_from(&self.line_buf, self.cursor)
     }
     #[inline]
+    fn line_number(&self) -> usize {
+        self.line_number
+    }
+    #[inline]
     unsafe fn skip(&mut self, n: usize) {
         self.cursor += n;
         debug_assert!(self.line_buf.is_char_boundary(self.cursor));
@@ -42,6 +49,9 @@ impl<B: BufRead> BufReadExt<char> for InputStream<B> {
         self.cursor = 0;
         let i = self.buffer.read_line(&mut self.line_buf)?;
         debug_assert!(self.line_buf.is_char_boundary(self.cursor));
+        if i > 0 {
+            self.line_number += 1;
+        }
         Ok(i > 0)
     }
     #[inline]
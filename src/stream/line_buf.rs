@@ -81,6 +81,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn line_number() {
+        let mut stream = LineBuf::new("1 2");
+        assert_eq!(stream.try_get().unwrap(), '1');
+        assert_eq!(stream.line_number(), 0);
+    }
+
     #[test]
     fn try_get_string() {
         let s = "Hello, world!";
@@ -1,6 +1,7 @@
 use crate::utf8char::FixedUtf8Char;
 use error::StreamError;
 
+pub(super) mod block_buf;
 pub(super) mod error;
 pub mod ext;
 pub(super) mod input_stream;
@@ -1,4 +1,5 @@
 use super::{
+    block_buf::get_block,
     error::StreamError,
     ext::{CharExt, CharSet, Pattern, PatternError, StrExt},
 };
@@ -42,6 +43,14 @@ where
     #[must_use = "This method returns whether a new line is read, and should be checked."]
     fn read_buf(&mut self) -> Result<bool, StreamError>;
 
+    /// Get the number of current line, counted from 1.
+    ///
+    /// Returns 0 if no line has been read, or if the stream does not count lines.
+    #[inline]
+    fn line_number(&self) -> usize {
+        0
+    }
+
     /// Check whether is at the end of the line.
     #[inline]
     fn is_eol(&self) -> bool {
@@ -174,6 +183,18 @@ where
             self.fill_buf()?;
         }
     }
+
+    /// Get the next block, which is a group of consecutive lines that are not blank, with each line terminated by `'\n'`.
+    ///
+    /// A blank line contains only whitespaces, as in [ReadOneFrom::try_read_mat_auto_from].
+    /// Blank lines before the block are skipped, and the blank line after the block is consumed.
+    ///
+    /// [ReadOneFrom::try_read_mat_auto_from]: crate::ReadOneFrom::try_read_mat_auto_from
+    #[inline]
+    fn try_get_block(&mut self) -> Result<String, StreamError> {
+        let (_, block) = get_block(self)?;
+        Ok(block)
+    }
 }
 
 impl<S: ?Sized + BufReadExt<Char>, Char> BufReadExt<Char> for &mut S
@@ -187,6 +208,10 @@ where
         S::get_cur_line(self)
     }
     #[inline]
+    fn line_number(&self) -> usize {
+        S::line_number(self)
+    }
+    #[inline]
     unsafe fn skip(&mut self, n: usize) {
         S::skip(self, n)
     }
@@ -0,0 +1,307 @@
+use crate::{
+    check::{compare_tokens, Mismatch, Verdict},
+    gen::Rng,
+    stdio::redirect,
+    unwrap, InputStream, WriteInto,
+};
+use std::{
+    fmt::{self, Display},
+    io::{self, Cursor},
+};
+
+/// An input on which two solutions produce different outputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Seed of the [Rng] that generated the input.
+    pub seed: u64,
+    /// The failing input, which is shrunk if shrinking is enabled.
+    pub input: String,
+    /// Output of the brute-force solution.
+    pub expected: String,
+    /// Output of the fast solution.
+    pub found: String,
+    /// The first mismatching token.
+    pub mismatch: Mismatch,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            seed,
+            input,
+            expected,
+            found,
+            mismatch,
+        } = self;
+        write!(
+            f,
+            "solutions differ on seed {seed} at {mismatch}\n\
+             input:\n{input}\n\
+             expected output:\n{expected}\n\
+             found output:\n{found}",
+        )
+    }
+}
+
+/// Error during stress testing with [try_stress].
+#[derive(Debug)]
+pub enum StressError {
+    /// Error during writing the generated input.
+    IOError(io::Error),
+    /// The two solutions produce different outputs.
+    Differ(Box<Counterexample>),
+}
+
+impl Display for StressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IOError(error) => Display::fmt(error, f),
+            Self::Differ(counterexample) => Display::fmt(counterexample, f),
+        }
+    }
+}
+
+impl std::error::Error for StressError {}
+
+impl From<io::Error> for StressError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::IOError(error)
+    }
+}
+
+/// Values that can be shrunk into smaller candidates, for minimizing failing inputs.
+pub trait Shrink: Sized {
+    /// Get smaller candidates of `self`, from the most aggressive to the least.
+    ///
+    /// Each candidate must be strictly smaller than `self` in some well-founded order,
+    /// so that shrinking always terminates.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_for_int {
+    ($($t:ty)*) => {
+        $(
+            /// Shrink towards zero.
+            impl Shrink for $t {
+                #[inline]
+                fn shrink(&self) -> Vec<Self> {
+                    let x = *self;
+                    if x == 0 {
+                        return Vec::new();
+                    }
+                    let mut candidates = vec![0, x / 2, if x > 0 { x - 1 } else { x + 1 }];
+                    candidates.dedup();
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_shrink_for_int!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Shrink by removing elements first, and then by shrinking each element.
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let n = self.len();
+        let mut candidates = Vec::new();
+        if n >= 2 {
+            candidates.push(self[n / 2..].to_vec());
+            candidates.push(self[..n / 2].to_vec());
+        }
+        for i in 0..n {
+            let mut candidate = self.clone();
+            let _: T = candidate.remove(i);
+            candidates.push(candidate);
+        }
+        for (i, x) in self.iter().enumerate() {
+            for x in x.shrink() {
+                let mut candidate = self.clone();
+                candidate[i] = x;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Run `solve` with `input` as its standard input, and capture its standard output.
+fn run(solve: &mut impl FnMut(), input: &str) -> String {
+    let ((), output) = redirect(Cursor::new(input.to_owned()), solve);
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+/// Write `value` as an input, ending with a newline as [show!](crate::show!) does.
+fn write_input(value: &impl WriteInto) -> io::Result<String> {
+    let mut input = value.try_write_into_string()?;
+    input.push('\n');
+    Ok(input)
+}
+
+/// Run both solutions on `input`, and compare their outputs.
+fn diff(
+    input: &str,
+    brute: &mut impl FnMut(),
+    fast: &mut impl FnMut(),
+) -> Option<(String, String, Mismatch)> {
+    let expected = run(brute, input);
+    let found = run(fast, input);
+    let mut e = InputStream::new(expected.as_bytes());
+    let mut o = InputStream::new(found.as_bytes());
+    // Reading from memory never fails.
+    match compare_tokens(&mut e, &mut o) {
+        Ok(Verdict::WrongAnswer(mismatch)) => Some((expected, found, mismatch)),
+        Ok(Verdict::Accepted) | Err(_) => None,
+    }
+}
+
+fn try_stress_by<T: WriteInto>(
+    mut gen: impl FnMut(&mut Rng) -> T,
+    mut brute: impl FnMut(),
+    mut fast: impl FnMut(),
+    iterations: usize,
+    mut shrink: impl FnMut(T) -> Vec<T>,
+) -> Result<(), StressError> {
+    for seed in 0..iterations as u64 {
+        let mut value = gen(&mut Rng::new(seed));
+        let mut input = write_input(&value)?;
+        let Some(mut differ) = diff(&input, &mut brute, &mut fast) else {
+            continue;
+        };
+        'shrink: loop {
+            for candidate in shrink(value) {
+                let candidate_input = write_input(&candidate)?;
+                if let Some(d) = diff(&candidate_input, &mut brute, &mut fast) {
+                    (value, input, differ) = (candidate, candidate_input, d);
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        let (expected, found, mismatch) = differ;
+        return Err(StressError::Differ(Box::new(Counterexample {
+            seed,
+            input,
+            expected,
+            found,
+            mismatch,
+        })));
+    }
+    Ok(())
+}
+
+/// Stress test `fast` against `brute` on `iterations` inputs generated by `gen`.
+///
+/// In the `i`-th iteration, `gen` is called with [`Rng::new(i)`](Rng::new), and the generated value is written
+/// with [WriteInto] in the default format, followed by a newline.
+/// For inputs in several parts, such as a length followed by an array, `gen` can return a formatted [String].
+/// Both solutions are run in current thread with [stdin](crate::stdin()) redirected to the input,
+/// and with [stdout](crate::stdout()) redirected into a buffer,
+/// so solutions written against [`read!`](crate::read!) and [`show!`](crate::show!) can be passed unchanged.
+/// Their outputs are compared with [compare_tokens].
+///
+/// Returns [StressError::Differ] on the first input on which the outputs differ.
+///
+/// # Panics
+///
+/// If a solution panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{read, show, try_stress, StressError};
+///
+/// fn brute() {
+///     let a: Vec<u32> = read!(3);
+///     show!(a.iter().sum::<u32>());
+/// }
+///
+/// fn fast() {
+///     let a: Vec<u32> = read!(3);
+///     show!(a[0] + a[1] + a[2] % 5);
+/// }
+///
+/// let err = try_stress(|rng| rng.ints(3, 0..10u32), brute, fast, 100).unwrap_err();
+/// assert!(matches!(err, StressError::Differ(_)));
+/// ```
+#[inline]
+pub fn try_stress<T: WriteInto>(
+    gen: impl FnMut(&mut Rng) -> T,
+    brute: impl FnMut(),
+    fast: impl FnMut(),
+    iterations: usize,
+) -> Result<(), StressError> {
+    try_stress_by(gen, brute, fast, iterations, |_| Vec::new())
+}
+
+/// Like [try_stress], but shrinks the failing input with [Shrink] before returning it.
+///
+/// Candidates are tried in order, and the first one on which the outputs still differ replaces the input,
+/// until no candidate fails.
+///
+/// # Panics
+///
+/// If a solution panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{read_all, show, try_stress_shrinking, StressError};
+///
+/// fn brute() {
+///     let a: Vec<u32> = read_all();
+///     show!(a.iter().max().unwrap_or(&0));
+/// }
+///
+/// fn fast() {
+///     let a: Vec<u32> = read_all();
+///     show!(a.first().unwrap_or(&0));
+/// }
+///
+/// let gen = |rng: &mut iof::gen::Rng| rng.ints(10, 0..100u32);
+/// let err = try_stress_shrinking(gen, brute, fast, 100).unwrap_err();
+/// let StressError::Differ(counterexample) = err else { unreachable!() };
+/// assert_eq!(counterexample.input, "0 1\n");
+/// ```
+#[inline]
+pub fn try_stress_shrinking<T: WriteInto + Shrink>(
+    gen: impl FnMut(&mut Rng) -> T,
+    brute: impl FnMut(),
+    fast: impl FnMut(),
+    iterations: usize,
+) -> Result<(), StressError> {
+    try_stress_by(gen, brute, fast, iterations, |value| value.shrink())
+}
+
+/// Unwrap the result of [try_stress].
+///
+/// # Panics
+///
+/// Panics with the failing input and both outputs if the outputs differ.
+#[inline]
+#[track_caller]
+pub fn stress<T: WriteInto>(
+    gen: impl FnMut(&mut Rng) -> T,
+    brute: impl FnMut(),
+    fast: impl FnMut(),
+    iterations: usize,
+) {
+    unwrap!(try_stress(gen, brute, fast, iterations))
+}
+
+/// Unwrap the result of [try_stress_shrinking].
+///
+/// # Panics
+///
+/// Panics with the shrunk failing input and both outputs if the outputs differ.
+#[inline]
+#[track_caller]
+pub fn stress_shrinking<T: WriteInto + Shrink>(
+    gen: impl FnMut(&mut Rng) -> T,
+    brute: impl FnMut(),
+    fast: impl FnMut(),
+    iterations: usize,
+) {
+    unwrap!(try_stress_shrinking(gen, brute, fast, iterations))
+}
@@ -24,7 +24,7 @@ fn iter_fixed_utf8_char() {
 
     let mut s = S;
 
-    for ((c, f), u) in c.into_iter().zip(f.into_iter()).zip(u.into_iter()) {
+    for ((c, f), u) in c.into_iter().zip(f).zip(u) {
         let string = c.to_string();
         let bytes = string.as_bytes();
         assert_eq!(Into::<char>::into(f), c);
@@ -0,0 +1,481 @@
+//! Strict input validation, for checking that test data follow the exact format.
+//!
+//! Unlike [ReadInto](crate::ReadInto), which skips any whitespaces between tokens,
+//! [StrictStream] requires every space, end of line and end of file to be read explicitly,
+//! so double spaces, trailing spaces and missing final newlines are all rejected.
+//! Numbers are checked against strict grammars, which reject leading zeros, `+` signs and `-0`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use iof::{validate::StrictStream, InputStream};
+//!
+//! let mut stream = StrictStream::new(InputStream::new("3 1\n".as_bytes()));
+//! let n: u32 = stream.read_int_in(1..=10, "n");
+//! stream.read_space();
+//! let k: u32 = stream.read_int_in(1..=n, "k");
+//! stream.read_eoln();
+//! stream.read_eof();
+//! assert_eq!((n, k), (3, 1));
+//!
+//! let mut stream = StrictStream::new(InputStream::new("03\n".as_bytes()));
+//! let err = stream.try_read_int_in(1..=10u32, "n").unwrap_err();
+//! assert_eq!(err.to_string(), "line 1, column 1: invalid n \"03\"");
+//! ```
+use crate::{
+    ext::{Pattern, State},
+    stream::error::StreamError,
+    unwrap, BufReadExt,
+};
+use std::{
+    convert::Infallible,
+    fmt::{self, Debug, Display},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+/// Position of a character in the input, both counted from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// Line number.
+    pub line: usize,
+    /// Column number, counted in characters.
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Error during validating input with [StrictStream].
+#[derive(Debug)]
+pub enum ValidateError {
+    /// Error during reading from input.
+    IOError(std::io::Error),
+    /// Found a character, or the end of file for [None], where another one is expected.
+    Unexpected {
+        /// Position of the found character.
+        position: Position,
+        /// Description of the expected input.
+        expected: &'static str,
+        /// The found character.
+        found: Option<char>,
+    },
+    /// A token does not match its grammar.
+    InvalidToken {
+        /// Position of the token.
+        position: Position,
+        /// Name of the token.
+        name: String,
+        /// The token.
+        token: String,
+    },
+    /// A token matches its grammar, but its value is out of range.
+    OutOfRange {
+        /// Position of the token.
+        position: Position,
+        /// Name of the token.
+        name: String,
+        /// The token.
+        token: String,
+        /// The expected range.
+        range: String,
+    },
+}
+
+impl Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IOError(error) => Display::fmt(error, f),
+            Self::Unexpected {
+                position,
+                expected,
+                found: Some(c),
+            } => write!(f, "{position}: expected {expected}, found {c:?}"),
+            Self::Unexpected {
+                position,
+                expected,
+                found: None,
+            } => write!(f, "{position}: expected {expected}, found end of file"),
+            Self::InvalidToken {
+                position,
+                name,
+                token,
+            } => write!(f, "{position}: invalid {name} {token:?}"),
+            Self::OutOfRange {
+                position,
+                name,
+                token,
+                range,
+            } => write!(f, "{position}: {name} = {token} is out of range {range}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidateError {}
+
+impl From<StreamError> for ValidateError {
+    #[inline]
+    fn from(error: StreamError) -> Self {
+        match error {
+            StreamError::IOError(e) => Self::IOError(e),
+            StreamError::Eof | StreamError::Eol => {
+                Self::IOError(std::io::ErrorKind::UnexpectedEof.into())
+            }
+        }
+    }
+}
+
+/// Grammar of strict integers, which is `0` or `-?[1-9][0-9]*`.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum StrictInteger {
+    /// Nothing is matched.
+    #[default]
+    Initial,
+    /// Matched `-`.
+    Minus,
+    /// Matched `0`.
+    Zero,
+    /// Matched a non-zero integer.
+    Digits,
+}
+
+impl Pattern for StrictInteger {
+    type Item = char;
+
+    #[inline]
+    fn step(&mut self, c: char) -> bool {
+        *self = match (*self, c) {
+            (Self::Initial, '-') => Self::Minus,
+            (Self::Initial, '0') => Self::Zero,
+            (Self::Initial | Self::Minus, '1'..='9') | (Self::Digits, '0'..='9') => Self::Digits,
+            _ => return false,
+        };
+        true
+    }
+
+    #[inline]
+    fn state(&self) -> State {
+        match self {
+            Self::Zero | Self::Digits => State::Stoppable,
+            Self::Initial | Self::Minus => State::Unfulfilled,
+        }
+    }
+}
+
+/// Grammar of strict real numbers, which is a strict integer with an optional fraction `\.[0-9]+`.
+///
+/// Besides, `-0` is allowed before a fraction.
+#[derive(Debug, Clone, Copy)]
+pub enum StrictReal {
+    /// Matched the integer part.
+    Integer(StrictInteger),
+    /// Matched `-0`.
+    MinusZero,
+    /// Matched `.`.
+    Point,
+    /// Matched digits in the fraction.
+    Fraction,
+}
+
+impl Default for StrictReal {
+    #[inline]
+    fn default() -> Self {
+        Self::Integer(StrictInteger::Initial)
+    }
+}
+
+impl Pattern for StrictReal {
+    type Item = char;
+
+    #[inline]
+    fn step(&mut self, c: char) -> bool {
+        *self = match (*self, c) {
+            (Self::Integer(StrictInteger::Minus), '0') => Self::MinusZero,
+            (Self::Integer(StrictInteger::Zero | StrictInteger::Digits) | Self::MinusZero, '.') => {
+                Self::Point
+            }
+            (Self::Integer(mut integer), _) => {
+                if !integer.step(c) {
+                    return false;
+                }
+                Self::Integer(integer)
+            }
+            (Self::Point | Self::Fraction, '0'..='9') => Self::Fraction,
+            _ => return false,
+        };
+        true
+    }
+
+    #[inline]
+    fn state(&self) -> State {
+        match self {
+            Self::Integer(integer) => integer.state(),
+            Self::Fraction => State::Stoppable,
+            Self::MinusZero | Self::Point => State::Unfulfilled,
+        }
+    }
+}
+
+/// Check whether `pattern` matches the whole `token`.
+#[inline]
+fn matches_whole<P: Pattern<Item = char>>(pattern: P, token: &str) -> bool {
+    matches!(pattern.forward::<Infallible>(token), Ok(len) if len == token.len())
+}
+
+/// Check whether `c` ends a token.
+#[inline]
+const fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// A stream that reads every character of the input explicitly, for validating input.
+///
+/// Each method has a `try_` version that returns a [ValidateError] with a [Position],
+/// and a version that panics with it.
+///
+/// A line ends with a single `'\n'`, and a token is a non-empty sequence of characters other than
+/// `' '`, `'\t'`, `'\r'` and `'\n'`.
+pub struct StrictStream<S> {
+    stream: S,
+    line: usize,
+    column: usize,
+}
+
+impl<S: BufReadExt> StrictStream<S> {
+    /// Create a strict stream from the beginning of `stream`.
+    #[inline]
+    pub const fn new(stream: S) -> Self {
+        let line = 0;
+        let column = 0;
+        Self {
+            stream,
+            line,
+            column,
+        }
+    }
+
+    /// Get the inner stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Get the position of the next character.
+    #[inline]
+    pub const fn position(&self) -> Position {
+        let line = if self.line == 0 { 1 } else { self.line };
+        let column = self.column + 1;
+        Position { line, column }
+    }
+
+    /// Peek the next character, or [None] at the end of file.
+    fn peek(&mut self) -> Result<Option<char>, ValidateError> {
+        if self.stream.is_eol() {
+            if !self.stream.read_buf()? {
+                return Ok(None);
+            }
+            self.line += 1;
+            self.column = 0;
+        }
+        Ok(self.stream.get_cur_line().chars().next())
+    }
+
+    /// Consume the next character if it is `expected`.
+    fn expect(&mut self, expected: char, description: &'static str) -> Result<(), ValidateError> {
+        match self.peek()? {
+            Some(c) if c == expected => {
+                unsafe { self.stream.skip(c.len_utf8()) };
+                self.column += 1;
+                Ok(())
+            }
+            found => Err(ValidateError::Unexpected {
+                position: self.position(),
+                expected: description,
+                found,
+            }),
+        }
+    }
+
+    /// Read a token, and return it with its position.
+    fn token(&mut self) -> Result<(String, Position), ValidateError> {
+        let found = self.peek()?;
+        let position = self.position();
+        let line = self.stream.get_cur_line();
+        let len = line.find(is_space).unwrap_or(line.len());
+        if len == 0 {
+            return Err(ValidateError::Unexpected {
+                position,
+                expected: "a token",
+                found,
+            });
+        }
+        let token = line[..len].to_owned();
+        unsafe { self.stream.skip(len) };
+        self.column += token.chars().count();
+        Ok((token, position))
+    }
+
+    /// Read a single space.
+    #[inline]
+    pub fn try_read_space(&mut self) -> Result<(), ValidateError> {
+        self.expect(' ', "a space")
+    }
+
+    /// Read the end of line, which is a single `'\n'`.
+    #[inline]
+    pub fn try_read_eoln(&mut self) -> Result<(), ValidateError> {
+        self.expect('\n', "end of line")
+    }
+
+    /// Check that the end of file is reached.
+    #[inline]
+    pub fn try_read_eof(&mut self) -> Result<(), ValidateError> {
+        match self.peek()? {
+            None => Ok(()),
+            found => Err(ValidateError::Unexpected {
+                position: self.position(),
+                expected: "end of file",
+                found,
+            }),
+        }
+    }
+
+    /// Read a token that matches `pattern` as a whole.
+    #[inline]
+    pub fn try_read_token_matching(
+        &mut self,
+        pattern: impl Pattern<Item = char>,
+        name: &str,
+    ) -> Result<String, ValidateError> {
+        let (token, position) = self.token()?;
+        if !matches_whole(pattern, &token) {
+            return Err(ValidateError::InvalidToken {
+                position,
+                name: name.to_owned(),
+                token,
+            });
+        }
+        Ok(token)
+    }
+
+    /// Read a strict integer in `range`. See [StrictInteger].
+    #[inline]
+    pub fn try_read_int_in<T>(
+        &mut self,
+        range: RangeInclusive<T>,
+        name: &str,
+    ) -> Result<T, ValidateError>
+    where
+        T: FromStr + PartialOrd + Debug,
+    {
+        let (token, position) = self.token()?;
+        if !matches_whole(StrictInteger::Initial, &token) {
+            return Err(ValidateError::InvalidToken {
+                position,
+                name: name.to_owned(),
+                token,
+            });
+        }
+        match token.parse::<T>() {
+            Ok(value) if range.contains(&value) => Ok(value),
+            _ => Err(ValidateError::OutOfRange {
+                position,
+                name: name.to_owned(),
+                token,
+                range: format!("{range:?}"),
+            }),
+        }
+    }
+
+    /// Read a strict real number in `range`, with the number of digits after the decimal point in `digits`.
+    /// See [StrictReal].
+    #[inline]
+    pub fn try_read_real_in(
+        &mut self,
+        range: RangeInclusive<f64>,
+        digits: RangeInclusive<usize>,
+        name: &str,
+    ) -> Result<f64, ValidateError> {
+        let (token, position) = self.token()?;
+        let fraction = token
+            .split_once('.')
+            .map_or(0, |(_, fraction)| fraction.len());
+        let negative_zero = token.starts_with('-') && !token.contains(|c| matches!(c, '1'..='9'));
+        if !matches_whole(StrictReal::default(), &token)
+            || !digits.contains(&fraction)
+            || negative_zero
+        {
+            return Err(ValidateError::InvalidToken {
+                position,
+                name: name.to_owned(),
+                token,
+            });
+        }
+        match token.parse::<f64>() {
+            Ok(value) if range.contains(&value) => Ok(value),
+            _ => Err(ValidateError::OutOfRange {
+                position,
+                name: name.to_owned(),
+                token,
+                range: format!("{range:?}"),
+            }),
+        }
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_space].
+    #[inline]
+    #[track_caller]
+    pub fn read_space(&mut self) {
+        unwrap!(self.try_read_space())
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_eoln].
+    #[inline]
+    #[track_caller]
+    pub fn read_eoln(&mut self) {
+        unwrap!(self.try_read_eoln())
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_eof].
+    #[inline]
+    #[track_caller]
+    pub fn read_eof(&mut self) {
+        unwrap!(self.try_read_eof())
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_token_matching].
+    #[inline]
+    #[track_caller]
+    pub fn read_token_matching(
+        &mut self,
+        pattern: impl Pattern<Item = char>,
+        name: &str,
+    ) -> String {
+        unwrap!(self.try_read_token_matching(pattern, name))
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_int_in].
+    #[inline]
+    #[track_caller]
+    pub fn read_int_in<T>(&mut self, range: RangeInclusive<T>, name: &str) -> T
+    where
+        T: FromStr + PartialOrd + Debug,
+    {
+        unwrap!(self.try_read_int_in(range, name))
+    }
+
+    /// Unwrap the result of [StrictStream::try_read_real_in].
+    #[inline]
+    #[track_caller]
+    pub fn read_real_in(
+        &mut self,
+        range: RangeInclusive<f64>,
+        digits: RangeInclusive<usize>,
+        name: &str,
+    ) -> f64 {
+        unwrap!(self.try_read_real_in(range, digits, name))
+    }
+}
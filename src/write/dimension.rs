@@ -55,6 +55,10 @@ impl<T: Dimension> Dimension for Vec<T> {
     const DIMENSION: usize = T::DIMENSION + 1;
     const SPACE: bool = T::SPACE;
 }
+impl<T: Dimension> Dimension for crate::Mat<T> {
+    const DIMENSION: usize = T::DIMENSION + 2;
+    const SPACE: bool = T::SPACE;
+}
 impl<T: Dimension> Dimension for [T] {
     const DIMENSION: usize = T::DIMENSION + 1;
     const SPACE: bool = T::SPACE;
@@ -83,3 +87,11 @@ impl<T: Dimension> Dimension for std::collections::BinaryHeap<T> {
     const DIMENSION: usize = T::DIMENSION + 1;
     const SPACE: bool = T::SPACE;
 }
+impl<K: Dimension, V: Dimension> Dimension for std::collections::HashMap<K, V> {
+    const DIMENSION: usize = K::DIMENSION + 2;
+    const SPACE: bool = true;
+}
+impl<K: Dimension, V: Dimension> Dimension for std::collections::BTreeMap<K, V> {
+    const DIMENSION: usize = K::DIMENSION + 2;
+    const SPACE: bool = true;
+}
@@ -0,0 +1,155 @@
+use super::{dimension::Dimension, separators::Separators, Result, WriteInto};
+use crate::{Align, FixedWidth, WidthUnit};
+use std::io::{self, Write};
+
+/// Values that can be written into fixed-width columns, with one field per element.
+pub trait WriteFixed {
+    /// Write each element into a string.
+    fn try_write_fields(&self) -> Result<Vec<String>>;
+}
+
+macro_rules! impl_write_fixed_for_tuple {
+    ($($n:ident $t:ident),+) => {
+        impl<$($t: WriteInto),+> WriteFixed for ($($t,)+) {
+            #[inline]
+            fn try_write_fields(&self) -> Result<Vec<String>> {
+                let ($($n,)+) = self;
+                Ok(vec![$($n.try_write_into_string()?),+])
+            }
+        }
+    };
+}
+
+impl_write_fixed_for_tuple!(t1 T1);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7, t8 T8);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7, t8 T8, t9 T9);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7, t8 T8, t9 T9, t10 T10);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7, t8 T8, t9 T9, t10 T10, t11 T11);
+impl_write_fixed_for_tuple!(t1 T1, t2 T2, t3 T3, t4 T4, t5 T5, t6 T6, t7 T7, t8 T8, t9 T9, t10 T10, t11 T11, t12 T12);
+
+impl<T: WriteInto> WriteFixed for [T] {
+    #[inline]
+    fn try_write_fields(&self) -> Result<Vec<String>> {
+        self.iter().map(WriteInto::try_write_into_string).collect()
+    }
+}
+
+impl<T: WriteInto> WriteFixed for Vec<T> {
+    #[inline]
+    fn try_write_fields(&self) -> Result<Vec<String>> {
+        self.as_slice().try_write_fields()
+    }
+}
+
+impl<T: WriteInto, const N: usize> WriteFixed for [T; N] {
+    #[inline]
+    fn try_write_fields(&self) -> Result<Vec<String>> {
+        self.as_slice().try_write_fields()
+    }
+}
+
+impl<T: WriteFixed + ?Sized> WriteFixed for &T {
+    #[inline]
+    fn try_write_fields(&self) -> Result<Vec<String>> {
+        T::try_write_fields(*self)
+    }
+}
+
+/// Write a value into fixed-width columns in a [FixedWidth] layout, which is the opposite of
+/// [ReadInto::try_read_fixed](crate::ReadInto::try_read_fixed).
+///
+/// Each element is padded with spaces according to the alignment of its field,
+/// and gaps between fields are filled with spaces. Separators are ignored.
+///
+/// Returns an [io::Error] of [io::ErrorKind::InvalidInput] if a value is wider than its field,
+/// if fields overlap, or if there are more elements than fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{Align, Fixed, FixedWidth, WriteInto};
+///
+/// let layout = FixedWidth::chars([0..4, 4..10, 11..16]).aligned([Align::Right, Align::Right, Align::Left]);
+/// let record = Fixed(&layout, (1, 12.5, "Bob"));
+/// assert_eq!(record.try_write_into_string().unwrap(), "   1  12.5 Bob  ");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fixed<'a, T>(pub &'a FixedWidth, pub T);
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn write_spaces<S: Write + ?Sized>(s: &mut S, n: usize) -> Result {
+    write!(s, "{:n$}", "")
+}
+
+impl<T> Dimension for Fixed<'_, T> {
+    const DIMENSION: usize = 1;
+    const SPACE: bool = false;
+}
+
+impl<T: WriteFixed> WriteInto for Fixed<'_, T> {
+    #[inline]
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        _sep: impl Separators,
+    ) -> Result {
+        let Self(layout, value) = self;
+        let values = value.try_write_fields()?;
+        let fields = layout.fields();
+        if values.len() > fields.len() {
+            return Err(invalid_input(format!(
+                "{} values can't be written into {} fields",
+                values.len(),
+                fields.len(),
+            )));
+        }
+        // Check every field first, so that a bad record writes nothing.
+        let mut pos = 0;
+        let mut lens = Vec::with_capacity(values.len());
+        for (i, ((range, _), value)) in fields.iter().zip(&values).enumerate() {
+            if range.start < pos {
+                return Err(invalid_input(format!(
+                    "field {i} overlaps the previous one"
+                )));
+            }
+            let width = range.end.saturating_sub(range.start);
+            let len = match layout.unit() {
+                WidthUnit::Byte => value.len(),
+                WidthUnit::Char => value.chars().count(),
+            };
+            if len > width {
+                return Err(invalid_input(format!(
+                    "value {value:?} is wider than field {i} of width {width}"
+                )));
+            }
+            lens.push(len);
+            pos = range.start + width;
+        }
+        let mut pos = 0;
+        for (((range, align), value), len) in fields.iter().zip(&values).zip(lens) {
+            write_spaces(s, range.start - pos)?;
+            let width = range.end.saturating_sub(range.start);
+            match align {
+                Align::Left => {
+                    s.write_all(value.as_bytes())?;
+                    write_spaces(s, width - len)?;
+                }
+                Align::Right => {
+                    write_spaces(s, width - len)?;
+                    s.write_all(value.as_bytes())?;
+                }
+            }
+            pos = range.start + width;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use super::{
+    dimension::Dimension, separator::Separator, separators::Separators, Result, WriteInto,
+};
+use crate::{GridCell, Mat};
+use std::io::Write;
+
+/// Write a character grid, with each row in a line and no separators between cells.
+///
+/// This is the opposite of [GridCell::try_read_grid_from],
+/// and [u8] cells are written as bytes rather than numbers.
+/// Only the separator between rows can be customized.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{Grid, Mat, WriteInto};
+///
+/// let grid = Mat::from([*b"#.#", *b"..#"]);
+/// assert_eq!(Grid(&grid).try_write_into_string().unwrap(), "#.#\n..#");
+/// assert_eq!(Grid(&grid).try_write_into_string_with_sep("/").unwrap(), "#.#/..#");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Grid<T>(pub T);
+
+fn try_write_rows<'a, T: GridCell + 'a, S: Write + ?Sized>(
+    rows: impl Iterator<Item = &'a [T]>,
+    s: &mut S,
+    sep: impl Separators,
+) -> Result {
+    let (sep, _) = sep.split();
+    for (i, row) in rows.enumerate() {
+        if i > 0 {
+            match &sep {
+                Some(sep) => sep.write_io(s)?,
+                None => s.write_all(b"\n")?,
+            }
+        }
+        T::try_write_row(row, s)?;
+    }
+    Ok(())
+}
+
+impl<T: GridCell> Dimension for Grid<&Mat<T>> {
+    const DIMENSION: usize = 2;
+    const SPACE: bool = false;
+}
+
+impl<T: GridCell> WriteInto for Grid<&Mat<T>> {
+    #[inline]
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        sep: impl Separators,
+    ) -> Result {
+        try_write_rows(self.0.iter(), s, sep)
+    }
+}
+
+impl<T: GridCell> Dimension for Grid<&[Vec<T>]> {
+    const DIMENSION: usize = 2;
+    const SPACE: bool = false;
+}
+
+impl<T: GridCell> WriteInto for Grid<&[Vec<T>]> {
+    #[inline]
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        sep: impl Separators,
+    ) -> Result {
+        try_write_rows(self.0.iter().map(Vec::as_slice), s, sep)
+    }
+}
+
+impl<T: GridCell> Dimension for Grid<&Vec<Vec<T>>> {
+    const DIMENSION: usize = 2;
+    const SPACE: bool = false;
+}
+
+impl<T: GridCell> WriteInto for Grid<&Vec<Vec<T>>> {
+    #[inline]
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        sep: impl Separators,
+    ) -> Result {
+        Grid(self.0.as_slice()).try_write_into_with_sep(s, sep)
+    }
+}
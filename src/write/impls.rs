@@ -1,9 +1,15 @@
 use super::{dimension::*, Separator, WriteInto};
-use crate::{impl_write_into_for_display, ASCIIChar, ASCIIString, Separators};
+use crate::{
+    impl_write_into_for_display,
+    radix::{AutoRadix, Bin, Hex, Oct},
+    ASCIIChar, ASCIIString, BigInt, BigUint, Decimal, Digits, Index1, Separators,
+};
 use std::{io, num::*};
 
 impl_write_into_for_display!(
     f32 f64
+    Decimal
+    BigInt BigUint Digits
     bool
 
     /* char ASCIIChar */
@@ -22,6 +28,24 @@ impl_write_into_for_display!(
     NonZeroIsize NonZeroUsize
 );
 
+macro_rules! impl_write_into_for_radix {
+    ($($wrapper:ident)*) => {
+        $(
+            impl_write_into_for_display!(
+                $wrapper<i8> $wrapper<i16> $wrapper<i32> $wrapper<i64> $wrapper<i128> $wrapper<isize>
+                $wrapper<u8> $wrapper<u16> $wrapper<u32> $wrapper<u64> $wrapper<u128> $wrapper<usize>
+            );
+        )*
+    };
+}
+
+impl_write_into_for_radix!(Hex Oct Bin AutoRadix);
+
+impl_write_into_for_display!(
+    Index1<i8> Index1<i16> Index1<i32> Index1<i64> Index1<i128> Index1<isize>
+    Index1<u8> Index1<u16> Index1<u32> Index1<u64> Index1<u128> Index1<usize>
+);
+
 impl WriteInto for char {
     #[inline]
     fn try_write_into_with_sep<S: io::Write + ?Sized>(
@@ -1,18 +1,21 @@
-use crate::{stdout, SepBy};
+use crate::{stdout, Mat, SepBy};
 use dimension::Dimension;
 use separator::Separator;
 use separators::{DefaultSeparator, Separators};
 use std::{
-    collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
     io::{self, Write},
 };
 
 pub mod dimension;
+pub(super) mod fixed;
+pub(super) mod grid;
 mod impls;
 mod macros;
 pub(super) mod sep_by;
 pub mod separator;
 pub(super) mod separators;
+pub(super) mod sorted;
 pub(super) mod writer;
 
 type Result<T = ()> = io::Result<T>;
@@ -22,10 +25,10 @@ type Result<T = ()> = io::Result<T>;
 /// - Most types that implement [std::fmt::Display] also implement this.
 /// - [Vec] and `[T]` where `T` implements [std::fmt::Display] also implements this.
 ///   They write each item separated by a space.
-/// - [Mat] where `T` implements [std::fmt::Display] also implements this.
+/// - [`Mat<T>`] where `T` implements [std::fmt::Display] also implements this.
 ///   They write each row separated by a newline, and each item in a row separated by a space.
-///
-/// [Mat]: crate::Mat
+/// - [HashMap] and [BTreeMap] where `K` and `V` implement [std::fmt::Display] also implement this.
+///   They write each `k v` pair separated by a newline, and the key and the value separated by a space.
 pub trait WriteInto: Dimension {
     /// Write into a stream with given separator.
     fn try_write_into_with_sep<S: Write + ?Sized>(&self, s: &mut S, sep: impl Separators)
@@ -107,12 +110,40 @@ macro_rules! impl_write_into_for_into_iter {
 }
 
 impl_write_into_for_into_iter!([T]);
+impl_write_into_for_into_iter!(Mat<T>);
 impl_write_into_for_into_iter!(HashSet<T>);
 impl_write_into_for_into_iter!(BTreeSet<T>);
 impl_write_into_for_into_iter!(VecDeque<T>);
 impl_write_into_for_into_iter!(BinaryHeap<T>);
 impl_write_into_for_into_iter!(LinkedList<T>);
 
+macro_rules! impl_write_into_for_map {
+    ($ty:ty) => {
+        impl<K: WriteInto, V: WriteInto> WriteInto for $ty {
+            #[inline]
+            fn try_write_into_with_sep<S: Write + ?Sized>(
+                &self,
+                s: &mut S,
+                sep: impl Separators,
+            ) -> Result<()> {
+                let (sep, residual) = sep.split();
+                if let Some(sep) = &sep {
+                    WriteInto::try_write_into_with_sep(&self.sep_by_write_into(sep), s, residual)
+                } else {
+                    WriteInto::try_write_into_with_sep(
+                        &self.sep_by_write_into(Self::get_default_separator()),
+                        s,
+                        residual,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_write_into_for_map!(HashMap<K, V>);
+impl_write_into_for_map!(BTreeMap<K, V>);
+
 macro_rules! impl_write_into_for_deref {
     ($ty:ty) => {
         impl<T: WriteInto + ?Sized> WriteInto for $ty {
@@ -0,0 +1,70 @@
+use super::{dimension::Dimension, separators::Separators, Result, WriteInto};
+use crate::SepBy;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+/// Write a [HashMap] sorted by keys, or a [HashSet] sorted by elements.
+///
+/// The iteration order of hash-based collections is unspecified,
+/// so wrap them in this to get reproducible output.
+///
+/// # Examples
+///
+/// ```rust
+/// use iof::{Sorted, WriteInto};
+/// use std::collections::HashMap;
+///
+/// let map = HashMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+/// assert_eq!(Sorted(&map).try_write_into_string().unwrap(), "1 a\n2 b\n3 c");
+/// assert_eq!(
+///     Sorted(&map).try_write_into_string_with_sep(&[", ", "="]).unwrap(),
+///     "1=a, 2=b, 3=c",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Sorted<T>(pub T);
+
+impl<K: Dimension, V: Dimension> Dimension for Sorted<&HashMap<K, V>> {
+    const DIMENSION: usize = <HashMap<K, V> as Dimension>::DIMENSION;
+    const SPACE: bool = <HashMap<K, V> as Dimension>::SPACE;
+}
+
+impl<K: WriteInto + Ord, V: WriteInto> WriteInto for Sorted<&HashMap<K, V>> {
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        sep: impl Separators,
+    ) -> Result {
+        let mut pairs: Vec<_> = self.0.iter().collect();
+        pairs.sort_unstable_by_key(|&(key, _)| key);
+        let (sep, residual) = sep.split();
+        if let Some(sep) = &sep {
+            pairs
+                .sep_by_write_into(sep)
+                .try_write_into_with_sep(s, residual)
+        } else {
+            pairs
+                .sep_by_write_into(Self::get_default_separator())
+                .try_write_into_with_sep(s, residual)
+        }
+    }
+}
+
+impl<T: Dimension> Dimension for Sorted<&HashSet<T>> {
+    const DIMENSION: usize = <HashSet<T> as Dimension>::DIMENSION;
+    const SPACE: bool = <HashSet<T> as Dimension>::SPACE;
+}
+
+impl<T: WriteInto + Ord> WriteInto for Sorted<&HashSet<T>> {
+    fn try_write_into_with_sep<S: Write + ?Sized>(
+        &self,
+        s: &mut S,
+        sep: impl Separators,
+    ) -> Result {
+        let mut items: Vec<_> = self.0.iter().collect();
+        items.sort_unstable();
+        items.try_write_into_with_sep(s, sep)
+    }
+}
@@ -0,0 +1,99 @@
+use iof::*;
+use std::io::Cursor;
+
+#[test]
+fn read_write_long() {
+    let a = "9".repeat(100_000);
+    let input = format!("{a} -{a}\n1");
+    let mut reader = InputStream::new(Cursor::new(input.as_bytes()));
+
+    let (x, y, z): (BigUint, BigInt, BigUint) = reader.read();
+    assert_eq!(unwrap!(x.try_write_into_string()), a);
+    assert_eq!(unwrap!(y.try_write_into_string()), format!("-{a}"));
+
+    let sum = &x + &z;
+    let s = unwrap!(sum.try_write_into_string());
+    assert_eq!(s.len(), 100_001);
+    assert!(s.starts_with('1') && s[1..].bytes().all(|b| b == b'0'));
+    assert_eq!(&sum - &z, x);
+    assert_eq!(&y + &BigInt::from(x), BigInt::ZERO);
+}
+
+#[test]
+fn read_n() {
+    let reader = Cursor::new("0 12 000345 18446744073709551616".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<BigUint> = read!(4; src = reader);
+    assert_eq!(
+        a,
+        [
+            BigUint::ZERO,
+            BigUint::from(12u32),
+            BigUint::from(345u32),
+            BigUint::from(1u128 << 64),
+        ]
+    );
+    assert_eq!(
+        unwrap!(a.try_write_into_string()),
+        "0 12 345 18446744073709551616"
+    );
+}
+
+#[test]
+fn read_digits() {
+    let reader = Cursor::new("0123 9\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<Digits> = read!(2; src = reader);
+    assert_eq!(a, [Digits(vec![0, 1, 2, 3]), Digits(vec![9])]);
+    assert_eq!(a[0].iter().map(|&d| d as u32).sum::<u32>(), 6);
+    assert_eq!(unwrap!(a.try_write_into_string()), "0123 9");
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"-\" during converting it to a value of \"iof::bigint::BigUint\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"-1\" to a value of `iof::bigint::BigUint`: invalid digit found in string"
+)]
+fn read_sign_error() {
+    let reader = Cursor::new("-1".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: BigUint = reader.read();
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"+\" during converting it to a value of \"iof::bigint::Digits\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"+12\" to a value of `iof::bigint::Digits`: invalid digit found in string"
+)]
+fn read_digits_sign_error() {
+    let reader = Cursor::new("+12".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Digits = reader.read();
+}
+
+#[test]
+#[cfg(feature = "c-compatible")]
+fn read_truncated() {
+    let reader = Cursor::new("123abc -45,".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Digits = reader.read();
+    assert_eq!(*a, [1, 2, 3]);
+    assert_eq!(reader.get_cur_line(), "abc -45,");
+    let _: [char; 3] = reader.read();
+    let b: BigInt = reader.read();
+    assert_eq!(b, BigInt::from(-45i64));
+    assert_eq!(reader.get_cur_line(), ",");
+}
@@ -0,0 +1,129 @@
+use iof::{
+    fmt::Format, BufReadExt, InputStream, Mat, ReadError, ReadFrom, ReadFromError, ReadInto,
+    ReadOneFrom, ReadOneInto,
+};
+use std::io::Cursor;
+
+#[test]
+fn get_block() {
+    let reader = Cursor::new("\n\na b\nc\n\n\nd\ne".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    assert_eq!(reader.try_get_block().unwrap(), "a b\nc\n");
+    assert_eq!(reader.try_get_block().unwrap(), "d\ne\n");
+    assert!(reader.try_get_block().is_err());
+}
+
+#[test]
+fn get_block_whitespace_separator() {
+    let reader = Cursor::new(" \t\na b\nc\n  \nd\n  \n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    assert_eq!(reader.try_get_block().unwrap(), "a b\nc\n");
+    assert_eq!(reader.try_get_block().unwrap(), "d\n");
+    assert!(reader.try_get_block().is_err());
+}
+
+#[test]
+fn blocks_and_mat_auto_agree() {
+    let input = "1 2\n3 4\n  \n5 6\n";
+
+    let mut reader = InputStream::new(Cursor::new(input.as_bytes()));
+    let blocks: Vec<u32> = reader.read_blocks().collect();
+    assert_eq!(blocks, [1, 5]);
+
+    let mut reader = InputStream::new(Cursor::new(input.as_bytes()));
+    let first: Mat<u32> = reader.read_mat_auto();
+    let second: Mat<u32> = reader.read_mat_auto();
+    assert_eq!(first, [[1, 2], [3, 4]]);
+    assert_eq!(second, [[5, 6]]);
+}
+
+#[test]
+fn get_block_after_token() {
+    let reader = Cursor::new("2\n1 2\n3 4\n\n5\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let n: usize = reader.read();
+    assert_eq!(n, 2);
+    assert_eq!(reader.try_get_block().unwrap(), "1 2\n3 4\n");
+    let m: u32 = reader.read();
+    assert_eq!(m, 5);
+}
+
+#[test]
+fn read_blocks() {
+    let reader = Cursor::new("3\n1 2 3\n\n\n1\n4\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let blocks: Vec<(usize, Vec<u32>)> =
+        reader.read_blocks().take_while(|(n, _)| *n != 0).collect();
+    assert_eq!(blocks, [(3, vec![1, 2, 3]), (1, vec![4])]);
+}
+
+#[test]
+fn read_blocks_of_mat() {
+    let reader = Cursor::new("1 2\n3 4\n\n5 6\n7 8\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let blocks: Vec<[[u32; 2]; 2]> = reader.read_blocks().collect();
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(Mat::from(blocks[1]), [[5, 6], [7, 8]]);
+}
+
+#[test]
+fn rest_discarded() {
+    let reader = Cursor::new("1 2\n3\n\n4 5\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let blocks: Vec<u32> = reader.read_blocks().collect();
+    assert_eq!(blocks, [1, 4]);
+}
+
+#[test]
+fn eof_in_block() {
+    let reader = Cursor::new("1\n\n2 3\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut blocks = ReadInto::<(u32, u32)>::iter_blocks(&mut reader);
+    assert!(matches!(blocks.next(), Some(Err(ReadError::EOF))));
+    assert!(blocks.next().is_none());
+}
+
+#[test]
+fn empty() {
+    let reader = Cursor::new("\n\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    assert_eq!(ReadInto::<u32>::iter_blocks(&mut reader).count(), 0);
+}
+
+struct Rows(Mat<u32>);
+
+impl ReadFrom for Rows {
+    type ParseError = <u32 as ReadOneFrom>::ParseError;
+
+    fn try_read_from<F: Format, S: BufReadExt>(
+        stream: &mut S,
+        _format: F,
+    ) -> Result<Self, ReadFromError<Self>> {
+        stream.try_read_rows(2, 2).map(Self)
+    }
+}
+
+#[test]
+fn error_line_in_second_block() {
+    let reader = Cursor::new("1 2\n3 4\n\n\n5 6\n7\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut blocks = ReadInto::<Rows>::iter_blocks(&mut reader);
+    assert_eq!(blocks.next().unwrap().unwrap().0, [[1, 2], [3, 4]]);
+    assert!(matches!(
+        blocks.next(),
+        Some(Err(ReadError::TokenCount {
+            line: 6,
+            expected: 2,
+            found: 1,
+        })),
+    ));
+}
@@ -0,0 +1,122 @@
+use iof::{
+    cases_from, read, show, try_cases_from, CaseFormat, CasesError, InputStream, ReadError,
+    ReadInto,
+};
+use std::io::{self, Cursor, Write};
+
+fn run(input: &str, format: CaseFormat<'_>) -> String {
+    let mut input = InputStream::new(Cursor::new(input.as_bytes()));
+    let mut output = Vec::new();
+    cases_from(&mut input, &mut output, format, |_, input, out| {
+        let n: usize = read!(; src = *input);
+        let a: Vec<u32> = read!(n; src = *input);
+        show!(a.iter().sum::<u32>() => *out);
+    });
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn inline() {
+    assert_eq!(
+        run("3\n2 1 2\n0\n1 5\n", CaseFormat::inline()),
+        "Case #1: 3\nCase #2: 0\nCase #3: 5\n"
+    );
+    assert_eq!(CaseFormat::default(), CaseFormat::inline());
+}
+
+#[test]
+fn multiline() {
+    assert_eq!(
+        run("2\n2 1 2\n1 5\n", CaseFormat::multiline()),
+        "Case #1:\n3\nCase #2:\n5\n"
+    );
+}
+
+#[test]
+fn custom() {
+    let format = CaseFormat::new("#", " ");
+    assert_eq!((format.prefix(), format.suffix()), ("#", " "));
+    assert_eq!(run("2 1 7 1 8", format), "#1 7\n#2 8\n");
+}
+
+#[test]
+fn case_numbers() {
+    let mut input = InputStream::new(Cursor::new("3 a b c".as_bytes()));
+    let mut output = Vec::new();
+    cases_from(
+        &mut input,
+        &mut output,
+        CaseFormat::new("", ""),
+        |k, input, out| {
+            let s: String = input.read();
+            writeln!(out, "{s}{k}").unwrap();
+        },
+    );
+    assert_eq!(output, b"1a1\n2b2\n3c3\n");
+}
+
+#[test]
+fn zero() {
+    assert_eq!(run("0\n", CaseFormat::inline()), "");
+}
+
+#[test]
+fn invalid_count() {
+    let mut input = InputStream::new(Cursor::new("x\n".as_bytes()));
+    let mut output = Vec::new();
+    let err =
+        try_cases_from(&mut input, &mut output, CaseFormat::inline(), |_, _, _| {}).unwrap_err();
+    assert!(matches!(
+        err,
+        CasesError::ReadCount(ReadError::FromStrError(..) | ReadError::UnexpectedChar(..))
+    ));
+    assert!(output.is_empty());
+}
+
+struct BrokenPipe;
+
+impl Write for BrokenPipe {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_error() {
+    let mut input = InputStream::new(Cursor::new(
+        "1
+"
+        .as_bytes(),
+    ));
+    let err = try_cases_from(
+        &mut input,
+        &mut BrokenPipe,
+        CaseFormat::inline(),
+        |_, _, _| unreachable!(),
+    )
+    .unwrap_err();
+    let CasesError::IOError(err) = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+#[should_panic = "case #2 panicked: odd"]
+fn panic_in_case() {
+    let mut input = InputStream::new(Cursor::new("2\n2\n3\n".as_bytes()));
+    let mut output = Vec::new();
+    cases_from(
+        &mut input,
+        &mut output,
+        CaseFormat::inline(),
+        |_, input, _| {
+            let x: u32 = input.read();
+            assert!(x % 2 == 0, "odd");
+        },
+    );
+}
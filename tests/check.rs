@@ -0,0 +1,100 @@
+use iof::{
+    check::{compare_floats, compare_lines, compare_tokens, Mismatch, Verdict},
+    InputStream,
+};
+use std::io::Cursor;
+
+fn stream(s: &str) -> InputStream<Cursor<&[u8]>> {
+    InputStream::new(Cursor::new(s.as_bytes()))
+}
+
+fn tokens(expected: &str, output: &str) -> Verdict {
+    compare_tokens(&mut stream(expected), &mut stream(output)).unwrap()
+}
+
+fn lines(expected: &str, output: &str) -> Verdict {
+    compare_lines(&mut stream(expected), &mut stream(output)).unwrap()
+}
+
+fn floats(expected: &str, output: &str, eps: f64) -> Verdict {
+    compare_floats(&mut stream(expected), &mut stream(output), eps).unwrap()
+}
+
+fn mismatch(line: usize, token: usize, expected: Option<&str>, found: Option<&str>) -> Verdict {
+    Verdict::WrongAnswer(Mismatch {
+        line,
+        token,
+        expected: expected.map(str::to_owned),
+        found: found.map(str::to_owned),
+    })
+}
+
+#[test]
+fn tokens_whitespace() {
+    assert_eq!(tokens("1 2\n3\n", "1\n2   3"), Verdict::Accepted);
+    assert_eq!(tokens("", "\n\n"), Verdict::Accepted);
+}
+
+#[test]
+fn tokens_yes_no() {
+    assert_eq!(tokens("YES NO", "yes No"), Verdict::Accepted);
+    assert_eq!(
+        tokens("Alice", "alice"),
+        mismatch(1, 1, Some("Alice"), Some("alice"))
+    );
+    assert_eq!(
+        tokens("YES", "YESS"),
+        mismatch(1, 1, Some("YES"), Some("YESS"))
+    );
+}
+
+#[test]
+fn tokens_mismatch() {
+    assert_eq!(
+        tokens("1 2 3", "1\n2\n4\n"),
+        mismatch(3, 3, Some("3"), Some("4"))
+    );
+    assert_eq!(tokens("1 2 3", "1 2"), mismatch(1, 3, Some("3"), None));
+    assert_eq!(tokens("1 2", "1\n2\n3"), mismatch(3, 3, None, Some("3")));
+}
+
+#[test]
+fn lines_whitespace() {
+    assert_eq!(lines("1 2\n3\n", "1  2 \n3"), Verdict::Accepted);
+    assert_eq!(lines("1 2\n3\n", "1 2\n3\n\n\n"), Verdict::Accepted);
+    assert_eq!(lines("1 2\n3\n\n", "1 2\n3"), Verdict::Accepted);
+}
+
+#[test]
+fn lines_mismatch() {
+    assert_eq!(
+        lines("1 2\n3\n", "1\n2 3\n"),
+        mismatch(1, 2, Some("2"), None)
+    );
+    assert_eq!(lines("1\n\n2\n", "1\n2\n"), mismatch(2, 1, None, Some("2")));
+    assert_eq!(lines("1\n2\n", "1\n"), mismatch(2, 1, Some("2"), None));
+}
+
+#[test]
+fn floats_eps() {
+    assert_eq!(floats("3.14159 YES", "3.1416 yes", 1e-4), Verdict::Accepted);
+    assert_eq!(floats("1000000", "1000001", 1e-6), Verdict::Accepted);
+    assert_eq!(
+        floats("1.5", "1.6", 1e-6),
+        mismatch(1, 1, Some("1.5"), Some("1.6"))
+    );
+    assert_eq!(
+        floats("1.5", "x", 1e-6),
+        mismatch(1, 1, Some("1.5"), Some("x"))
+    );
+    assert_eq!(floats("x", "x", 1e-6), Verdict::Accepted);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Verdict::Accepted.to_string(), "accepted");
+    assert_eq!(
+        tokens("1 2", "1").to_string(),
+        "wrong answer at line 1, token 2: expected \"2\", found end of output"
+    );
+}
@@ -0,0 +1,112 @@
+use iof::{fmt::Default, *};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
+    io::Cursor,
+};
+
+#[test]
+fn read_line_form() {
+    let reader = Cursor::new("3 1 2 1\n4 5\n6 7 8\n1 x 2 y 1 z\n3 p\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: BTreeSet<u32> = reader.read();
+    assert_eq!(a, BTreeSet::from([1, 2, 3]));
+    let b: VecDeque<u32> = reader.read();
+    assert_eq!(b, [4, 5]);
+    let c: BinaryHeap<u32> = reader.read();
+    assert_eq!(c.into_sorted_vec(), [6, 7, 8]);
+    let d: HashMap<u32, char> = reader.read();
+    assert_eq!(d, HashMap::from([(1, 'z'), (2, 'y')]));
+    let e: BTreeMap<u32, char> = reader.read();
+    assert_eq!(e, BTreeMap::from([(3, 'p')]));
+
+    assert!(<HashSet<u32>>::try_read_from(&mut reader, Default::new()).is_err());
+}
+
+#[test]
+fn read_n_form() {
+    let reader = Cursor::new("1 2\n3 4\n5 6\n1 2 2 3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: BTreeMap<u32, u32> = read!(collect 3; src = reader);
+    assert_eq!(a, BTreeMap::from([(1, 2), (3, 4), (5, 6)]));
+    let b: HashSet<u32> = read!(collect 4; src = reader);
+    assert_eq!(b, HashSet::from([1, 2, 3]));
+}
+
+#[test]
+fn read_count_form() {
+    let reader = Cursor::new("1 a\n2 b\n1 c\n1 a\n2 b\n1 c\n1 a\n2 b\n1 c\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a = unwrap!(BTreeMap::<u32, char>::try_read_collection_n_from(
+        &mut reader,
+        3,
+        Default::new(),
+        Duplicate::KeepFirst,
+    ));
+    assert_eq!(a, BTreeMap::from([(1, 'a'), (2, 'b')]));
+
+    let b = unwrap!(HashMap::<u32, char>::try_read_collection_n_from(
+        &mut reader,
+        3,
+        Default::new(),
+        Duplicate::KeepLast,
+    ));
+    assert_eq!(b, HashMap::from([(1, 'c'), (2, 'b')]));
+
+    let c = HashMap::<u32, char>::try_read_collection_n_from(
+        &mut reader,
+        3,
+        Default::new(),
+        Duplicate::Error,
+    );
+    assert!(c
+        .unwrap_err()
+        .to_string()
+        .starts_with("found duplicate key in element 3 during reading a value of "));
+}
+
+#[test]
+fn read_sets_with_policy() {
+    let reader = Cursor::new("1 2 3\n3 2 3\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a = unwrap!(BTreeSet::<u32>::try_read_collection_in_line_from(
+        &mut reader,
+        Default::new(),
+        Duplicate::Error,
+    ));
+    assert_eq!(a, BTreeSet::from([1, 2, 3]));
+
+    let b = BTreeSet::<u32>::try_read_collection_in_line_from(
+        &mut reader,
+        Default::new(),
+        Duplicate::Error,
+    );
+    assert!(b
+        .unwrap_err()
+        .to_string()
+        .starts_with("found duplicate key in element 3 during reading a value of "));
+}
+
+#[test]
+fn read_with_csv() {
+    let reader = Cursor::new("a, 1, b, 2\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: BTreeMap<String, u32> = read!(; src = reader; fmt = fmt::csv());
+    assert_eq!(
+        a,
+        BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)])
+    );
+}
+
+#[test]
+#[should_panic = "expect more characters before EOL"]
+fn read_map_incomplete_pair() {
+    let reader = Cursor::new("1 a 2\n3 b".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: HashMap<u32, char> = reader.read();
+}
@@ -0,0 +1,88 @@
+use iof::{fmt::Default, read, InputStream, ReadColumns, ReadError, ReadInto};
+use std::io::Cursor;
+
+#[test]
+fn three_columns() {
+    let reader = Cursor::new("1 10 a\n2 20 b\n3 30 c\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (a, b, c) = ReadInto::<(i32, i64, char)>::read_columns(&mut reader, 3);
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(b, [10, 20, 30]);
+    assert_eq!(c, ['a', 'b', 'c']);
+}
+
+#[test]
+fn single_column() {
+    let reader = Cursor::new("1 2 3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (a,): (Vec<u8>,) = read!(columns 3; src = reader);
+    assert_eq!(a, [1, 2, 3]);
+}
+
+#[test]
+fn twelve_columns() {
+    let reader = Cursor::new("1 2 3 4 5 6 7 8 9 10 11 12\n".repeat(2));
+    let mut reader = InputStream::new(Cursor::new(reader.into_inner().into_bytes()));
+
+    type Row = (
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+    );
+    let columns = <Row>::try_read_columns_from(&mut reader, 2, Default::new());
+    let (c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12) = columns.unwrap();
+    assert_eq!(c1, [1, 1]);
+    assert_eq!(c2, [2, 2]);
+    assert_eq!(c3, [3, 3]);
+    assert_eq!(c4, [4, 4]);
+    assert_eq!(c5, [5, 5]);
+    assert_eq!(c6, [6, 6]);
+    assert_eq!(c7, [7, 7]);
+    assert_eq!(c8, [8, 8]);
+    assert_eq!(c9, [9, 9]);
+    assert_eq!(c10, [10, 10]);
+    assert_eq!(c11, [11, 11]);
+    assert_eq!(c12, [12, 12]);
+}
+
+#[test]
+fn macro_columns() {
+    let reader = Cursor::new("2\nalice,90\nbob,85".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let n: usize = read!(; src = reader);
+    let (names, scores): (Vec<String>, Vec<u32>) =
+        read!(columns n; src = reader; skip = [' ', ',', '\n']);
+    assert_eq!(names, ["alice", "bob"]);
+    assert_eq!(scores, [90, 85]);
+}
+
+#[test]
+fn empty() {
+    let reader = Cursor::new("".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (a, b): (Vec<u32>, Vec<u32>) = read!(columns 0; src = reader);
+    assert!(a.is_empty());
+    assert!(b.is_empty());
+}
+
+#[test]
+fn error() {
+    let reader = Cursor::new("1 a\n2".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let res = ReadInto::<(u32, char)>::try_read_columns(&mut reader, 2);
+    assert!(matches!(res, Err(ReadError::EOF)));
+}
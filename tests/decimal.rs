@@ -0,0 +1,76 @@
+use iof::*;
+use std::io::Cursor;
+
+#[test]
+fn read_write() {
+    let reader = Cursor::new("12.340 -0.5 7 .25\n100.\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<Decimal> = read!(5; src = reader);
+    assert_eq!(
+        a,
+        [
+            Decimal::new(1234, 2),
+            Decimal::new(-5, 1),
+            Decimal::from(7),
+            Decimal::new(25, 2),
+            Decimal::from(100),
+        ]
+    );
+    assert_eq!(a[0].scale(), 3);
+    assert_eq!(unwrap!(a.try_write_into_string()), "12.340 -0.5 7 0.25 100");
+}
+
+#[test]
+fn read_mat() {
+    let reader = Cursor::new("0.1 0.2\n0.3 0.4".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Mat<Decimal> = read!(mat 2, 2; src = reader);
+    let sum = a.iter().flatten().fold(Decimal::ZERO, |acc, x| acc + *x);
+    assert_eq!(sum, Decimal::from(1));
+    assert_eq!(sum.to_string(), "1.0");
+}
+
+#[test]
+fn round_trip_exact() {
+    let s = "3.14159265358979323846264338327950288";
+    let mut reader = InputStream::new(Cursor::new(s.as_bytes()));
+    let a: Decimal = reader.read();
+    assert_eq!(unwrap!(a.try_write_into_string()), s);
+    assert_eq!(
+        unwrap!(a.round(4, Rounding::HalfUp).try_write_into_string()),
+        "3.1416"
+    );
+    assert_eq!(
+        unwrap!(a.round(4, Rounding::Down).try_write_into_string()),
+        "3.1415"
+    );
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"e\" during converting it to a value of \"iof::decimal::Decimal\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"1e5\" to a value of `iof::decimal::Decimal`: invalid digit found in string"
+)]
+fn read_exponent() {
+    let reader = Cursor::new("1e5 e".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Decimal = reader.read();
+    assert_eq!(a, Decimal::from(1));
+    let _: Decimal = reader.read();
+}
+
+#[test]
+#[should_panic = "number too large to fit in target type"]
+fn read_overflow() {
+    let reader = Cursor::new("1234567890123456789012345678901234567890".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Decimal = reader.read();
+}
@@ -0,0 +1,125 @@
+use iof::{show, Align, Fixed, FixedWidth, InputStream, ReadError, ReadFixed, ReadInto, WriteInto};
+use std::io::Cursor;
+
+fn layout() -> FixedWidth {
+    FixedWidth::chars([0..6, 6..15, 15..25]).aligned([Align::Right, Align::Right, Align::Left])
+}
+
+#[test]
+fn read_records() {
+    let reader = Cursor::new("2\n000001    12.50Alice\n000002   100.00Bob\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+    let layout = layout();
+
+    let n: usize = reader.read();
+    let records: Vec<(u32, f64, String)> = (0..n).map(|_| reader.read_fixed(&layout)).collect();
+    assert_eq!(
+        records,
+        [(1, 12.5, "Alice".to_owned()), (2, 100.0, "Bob".to_owned()),],
+    );
+}
+
+#[test]
+fn read_vec() {
+    let reader = Cursor::new("  1 22333\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+    let layout = FixedWidth::bytes([0..3, 3..6, 6..9]);
+
+    let fields: Vec<u32> = reader.read_fixed(&layout);
+    assert_eq!(fields, [1, 22, 333]);
+}
+
+#[test]
+fn chars_and_bytes() {
+    let line = "αβγ12";
+    let by_chars = FixedWidth::chars([0..3, 3..5]);
+    let by_bytes = FixedWidth::bytes([0..6, 6..8]);
+    assert_eq!(
+        <(String, u8)>::try_parse_fixed(line, &by_chars).unwrap(),
+        ("αβγ".to_owned(), 12),
+    );
+    assert_eq!(
+        <(String, u8)>::try_parse_fixed(line, &by_bytes).unwrap(),
+        ("αβγ".to_owned(), 12),
+    );
+    let err = <(String,)>::try_parse_fixed(line, &FixedWidth::bytes([0..1, 1..2])).unwrap_err();
+    assert!(matches!(err, ReadError::UnexpectedChar(..)));
+}
+
+#[test]
+fn short_line() {
+    let layout = FixedWidth::bytes([0..2, 2..4, 4..6]);
+    let (a, b, c) = <(u8, u8, String)>::try_parse_fixed("12 3", &layout).unwrap();
+    assert_eq!((a, b, c.as_str()), (12, 3, ""));
+
+    let err = <(u8, u8, String, u8)>::try_parse_fixed("12 3", &layout).unwrap_err();
+    assert!(matches!(err, ReadError::EOL));
+}
+
+#[test]
+fn parse_error() {
+    let layout = FixedWidth::bytes([0..2, 2..4]);
+    let err = <(u8, u8)>::try_parse_fixed("1x 3", &layout).unwrap_err();
+    assert!(matches!(err, ReadError::FromStrError(..)));
+}
+
+#[test]
+fn write_records() {
+    let layout = layout();
+    let record = Fixed(&layout, (1, 12.5, "Alice"));
+    assert_eq!(
+        record.try_write_into_string().unwrap(),
+        "     1     12.5Alice     ",
+    );
+
+    let records = [
+        Fixed(&layout, (1u32, 12.5, "Alice")),
+        Fixed(&layout, (2u32, 100.0, "Bob")),
+    ];
+    let mut buf = Vec::new();
+    show!(records => &mut buf);
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "     1     12.5Alice     \n     2      100Bob       \n",
+    );
+}
+
+#[test]
+fn round_trip() {
+    let layout = layout();
+    let s = Fixed(&layout, (42, 3.25, "Carol"))
+        .try_write_into_string()
+        .unwrap();
+    let record: (u32, f64, String) = <_>::try_parse_fixed(&s, &layout).unwrap();
+    assert_eq!(record, (42, 3.25, "Carol".to_owned()));
+}
+
+#[test]
+fn write_errors() {
+    let layout = FixedWidth::chars([0..2, 2..4]);
+    assert!(Fixed(&layout, (123, 4)).try_write_into_string().is_err());
+    assert!(Fixed(&layout, [1, 2, 3]).try_write_into_string().is_err());
+
+    let overlapping = FixedWidth::chars([0..3, 2..4]);
+    assert!(Fixed(&overlapping, (1, 2)).try_write_into_string().is_err());
+
+    let layout = FixedWidth::chars([0..2, 2..4]);
+    assert_eq!(
+        Fixed(&layout, ["αβ", "γ"]).try_write_into_string().unwrap(),
+        "αβ γ"
+    );
+}
+
+#[test]
+fn write_error_writes_nothing() {
+    let layout = FixedWidth::chars([0..2, 2..4]);
+    let mut buf = Vec::new();
+    assert!(Fixed(&layout, (1, 234)).try_write_into(&mut buf).is_err());
+    assert!(buf.is_empty());
+
+    let overlapping = FixedWidth::chars([0..2, 1..4]);
+    assert!(Fixed(&overlapping, (1, 2))
+        .try_write_into(&mut buf)
+        .is_err());
+    assert!(buf.is_empty());
+}
@@ -171,3 +171,141 @@ fn try_read_array_4() {
         assert!(<u32>::try_read_one_from(&mut reader, Default::new()).is_err());
     }
 }
+
+/// A tiny xorshift generator, so that the fuzz tests are reproducible.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Generate a string that is likely to be close to a float literal.
+fn random_literal(rng: &mut XorShift) -> String {
+    const ALPHABET: &[u8] = b"0123456789012345678901234567890123456789+-.eE";
+    let mut s = String::new();
+    match rng.below(8) {
+        0 => s.push_str(["inf", "-infinity", "NaN", "+Inf", "nan", "in"][rng.below(6) as usize]),
+        1 => {
+            for _ in 0..rng.below(8) {
+                s.push(ALPHABET[rng.below(ALPHABET.len() as u64) as usize] as char);
+            }
+        }
+        _ => {
+            if rng.below(3) == 0 {
+                s.push(['+', '-'][rng.below(2) as usize]);
+            }
+            for _ in 0..rng.below(30) {
+                s.push((b'0' + rng.below(10) as u8) as char);
+            }
+            if rng.below(2) == 0 {
+                s.push('.');
+                for _ in 0..rng.below(30) {
+                    s.push((b'0' + rng.below(10) as u8) as char);
+                }
+            }
+            if rng.below(2) == 0 {
+                s.push(['e', 'E'][rng.below(2) as usize]);
+                if rng.below(2) == 0 {
+                    s.push(['+', '-'][rng.below(2) as usize]);
+                }
+                s.push_str(&rng.below(400).to_string());
+            }
+        }
+    }
+    s
+}
+
+fn check_same<T>(s: &str)
+where
+    T: ReadOneFrom<ParseError = std::num::ParseFloatError>
+        + std::str::FromStr<Err = std::num::ParseFloatError>
+        + std::fmt::Debug
+        + Into<f64>,
+{
+    match (<T as ReadOneFrom>::parse(s), s.parse::<T>()) {
+        (Ok(a), Ok(b)) => {
+            let (a, b): (f64, f64) = (a.into(), b.into());
+            assert!(
+                a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan(),
+                "{s:?}: {a:?} != {b:?}",
+            );
+        }
+        (Err(_), Err(_)) => {}
+        (a, b) => panic!("{s:?}: {a:?} != {b:?}"),
+    }
+}
+
+#[test]
+fn fuzz_literals() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50_000 {
+        let s = random_literal(&mut rng);
+        check_same::<f64>(&s);
+        check_same::<f32>(&s);
+    }
+}
+
+#[test]
+fn fuzz_round_trip() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..20_000 {
+        let bits = rng.next();
+        let a = f64::from_bits(bits);
+        let b = f32::from_bits(bits as u32);
+        for s in [
+            format!("{a}"),
+            format!("{a:e}"),
+            format!("{a:.3}"),
+            format!("{b}"),
+            format!("{b:e}"),
+        ] {
+            check_same::<f64>(&s);
+            check_same::<f32>(&s);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "c-compatible"))]
+fn fuzz_stream() {
+    let mut rng = XorShift(0x6a09_e667_f3bc_c908);
+    let literals: Vec<String> = (0..20_000).map(|_| random_literal(&mut rng)).collect();
+    let input = literals.join(" \n");
+    let mut reader = InputStream::new(input.as_bytes());
+    for s in literals.iter().filter(|s| !s.is_empty()) {
+        let a = <f64>::try_read_one_from(&mut reader, Default::new());
+        match (a, s.parse::<f64>()) {
+            (Ok(a), Ok(b)) => assert!(
+                a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan(),
+                "{s:?}: {a:?} != {b:?}",
+            ),
+            (Err(_), Err(_)) => {}
+            (a, b) => panic!("{s:?}: {a:?} != {b:?}"),
+        }
+    }
+}
+
+#[test]
+fn halfway() {
+    for s in [
+        "9007199254740993",
+        "9007199254740993.0000000000000000000000000001",
+        "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+        "7.038531e-26",
+        "340282356779733661637539395458142568448",
+        "1e-45",
+        "0.7e-45",
+    ] {
+        check_same::<f64>(s);
+        check_same::<f32>(s);
+    }
+}
@@ -0,0 +1,176 @@
+use iof::{gen::Rng, InputStream, ReadInto, WriteInto};
+use std::{collections::HashSet, io::Cursor};
+
+fn find(parent: &mut [usize], v: usize) -> usize {
+    if parent[v] != v {
+        parent[v] = find(parent, parent[v]);
+    }
+    parent[v]
+}
+
+/// Check that `edges` is a simple graph on `1..=n`, and return the number of connected components.
+fn components(n: usize, edges: &[(usize, usize)]) -> usize {
+    let mut parent: Vec<usize> = (0..=n).collect();
+    let mut seen = HashSet::new();
+    let mut count = n;
+    for &(a, b) in edges {
+        assert!((1..=n).contains(&a) && (1..=n).contains(&b) && a != b);
+        assert!(seen.insert((a.min(b), a.max(b))), "duplicate edge {a} {b}");
+        let (a, b) = (find(&mut parent, a), find(&mut parent, b));
+        if a != b {
+            parent[a] = b;
+            count -= 1;
+        }
+    }
+    count
+}
+
+#[test]
+fn deterministic() {
+    let a: Vec<u64> = Rng::new(7).ints(10, 0..u64::MAX);
+    let b: Vec<u64> = Rng::new(7).ints(10, 0..u64::MAX);
+    let c: Vec<u64> = Rng::new(8).ints(10, 0..u64::MAX);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn ints() {
+    let mut rng = Rng::new(1);
+    let a: Vec<i8> = rng.ints(1000, -3..=3);
+    assert!(a.iter().all(|x| (-3..=3).contains(x)));
+    assert_eq!(a.iter().collect::<HashSet<_>>().len(), 7);
+    let b: Vec<u32> = rng.ints(100, 5..6);
+    assert!(b.iter().all(|&x| x == 5));
+    let _: i64 = rng.int(i64::MIN..=i64::MAX);
+}
+
+#[test]
+#[should_panic = "cannot sample from an empty range"]
+fn empty_range() {
+    let _: u32 = Rng::new(0).int(3..3);
+}
+
+#[test]
+fn distinct() {
+    let mut rng = Rng::new(2);
+    let a: Vec<i32> = rng.distinct(10, -5..5);
+    let mut sorted = a.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (-5..5).collect::<Vec<_>>());
+    let b: Vec<u64> = rng.distinct(100, 1..=1_000_000_000_000);
+    assert_eq!(b.iter().collect::<HashSet<_>>().len(), 100);
+}
+
+#[test]
+#[should_panic = "cannot generate 4 distinct integers from a range of 3 integers"]
+fn distinct_too_many() {
+    let _: Vec<u8> = Rng::new(0).distinct(4, 0..3);
+}
+
+#[test]
+fn permutation() {
+    let mut p = Rng::new(3).permutation(20);
+    p.sort_unstable();
+    assert_eq!(p, (1..=20).collect::<Vec<_>>());
+    assert!(Rng::new(3).permutation(0).is_empty());
+}
+
+#[test]
+fn string() {
+    let s = Rng::new(4).string(50, "ab");
+    assert_eq!(s.len(), 50);
+    assert!(s.chars().all(|c| c == 'a' || c == 'b'));
+    assert_eq!(Rng::new(4).string(3, "é"), "ééé");
+}
+
+#[test]
+fn tree_parents() {
+    let p = Rng::new(5).tree_parents(10);
+    assert_eq!(p.len(), 9);
+    assert!(p.iter().enumerate().all(|(i, &p)| (1..i + 2).contains(&p)));
+    assert!(Rng::new(5).tree_parents(1).is_empty());
+}
+
+#[test]
+fn tree_edges() {
+    let mut rng = Rng::new(6);
+    for n in 1..30 {
+        let edges = rng.tree_edges(n);
+        assert_eq!(edges.len(), n - 1);
+        assert_eq!(components(n, &edges), 1);
+    }
+}
+
+#[test]
+fn connected_graph() {
+    let mut rng = Rng::new(7);
+    for (n, m) in [(1, 0), (5, 4), (5, 7), (5, 10), (100, 300), (30, 400)] {
+        let edges = rng.connected_graph(n, m);
+        assert_eq!(edges.len(), m);
+        assert_eq!(components(n, &edges), 1);
+    }
+}
+
+#[test]
+#[should_panic = "a connected graph on 5 vertices needs at least 4 edges"]
+fn connected_graph_too_few() {
+    let _ = Rng::new(0).connected_graph(5, 3);
+}
+
+#[test]
+fn dag() {
+    let mut rng = Rng::new(8);
+    for (n, m) in [(1, 0), (5, 10), (50, 100), (20, 150)] {
+        let edges = rng.dag(n, m);
+        assert_eq!(edges.len(), m);
+        let _ = components(n, &edges);
+        // Kahn's algorithm.
+        let mut indegree = vec![0; n + 1];
+        for &(_, b) in &edges {
+            indegree[b] += 1;
+        }
+        let mut queue: Vec<usize> = (1..=n).filter(|&v| indegree[v] == 0).collect();
+        let mut visited = 0;
+        while let Some(v) = queue.pop() {
+            visited += 1;
+            for &(a, b) in &edges {
+                if a == v {
+                    indegree[b] -= 1;
+                    if indegree[b] == 0 {
+                        queue.push(b);
+                    }
+                }
+            }
+        }
+        assert_eq!(visited, n);
+    }
+}
+
+#[test]
+#[should_panic = "cannot generate 11 more edges on 5 vertices"]
+fn dag_too_many() {
+    let _ = Rng::new(0).dag(5, 11);
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = Rng::new(9);
+    let (n, m) = (8, 12);
+    let a: Vec<i64> = rng.ints(n, -1_000_000_000..=1_000_000_000);
+    let s = rng.string(n, "abc");
+    let edges = rng.connected_graph(n, m);
+    let input = format!(
+        "{}\n{}\n{}\n{}\n",
+        (n, m).try_write_into_string().unwrap(),
+        a.try_write_into_string().unwrap(),
+        s.try_write_into_string().unwrap(),
+        edges.try_write_into_string().unwrap(),
+    );
+    let mut reader = InputStream::new(Cursor::new(input.as_bytes()));
+    let (n2, m2): (usize, usize) = reader.read();
+    let a2: Vec<i64> = reader.read_n(n2);
+    let s2: String = reader.read();
+    let edges2: Vec<(usize, usize)> = reader.read_n(m2);
+    assert_eq!((n2, m2, a2, s2, edges2), (n, m, a, s, edges));
+}
@@ -0,0 +1,136 @@
+use iof::{Csr, GraphLayout, InputStream, ReadError, ReadGraph, ReadInto};
+use std::io::Cursor;
+
+fn reader(s: &str) -> InputStream<Cursor<&[u8]>> {
+    InputStream::new(Cursor::new(s.as_bytes()))
+}
+
+#[test]
+fn undirected() {
+    let mut reader = reader("4 3\n1 2\n2 3\n4 2\n");
+    let (n, m): (usize, usize) = reader.read();
+    let g = reader.read_graph(n, m, GraphLayout::undirected());
+    assert_eq!(g, [vec![1], vec![0, 2, 3], vec![1], vec![1]]);
+}
+
+#[test]
+fn directed_zero_based() {
+    let layout = GraphLayout::directed().zero_based();
+    assert!(layout.is_directed());
+    assert_eq!(layout.base(), 0);
+    assert_eq!(layout.one_based().base(), 1);
+    let g = reader("0 1\n0 2\n2 1\n").read_graph(3, 3, layout);
+    assert_eq!(g, [vec![1, 2], vec![], vec![1]]);
+}
+
+#[test]
+fn default_layout() {
+    assert_eq!(GraphLayout::default(), GraphLayout::undirected());
+}
+
+#[test]
+fn weighted() {
+    let g: Vec<Vec<(usize, f64)>> =
+        reader("1 2 0.5\n2 3 1.5\n").read_weighted_graph(3, 2, GraphLayout::undirected());
+    assert_eq!(
+        g,
+        [vec![(1, 0.5)], vec![(0, 0.5), (2, 1.5)], vec![(1, 1.5)]]
+    );
+
+    let g: Vec<Vec<(usize, String)>> =
+        reader("1 2 a\n1 3 b\n").read_weighted_graph(3, 2, GraphLayout::directed());
+    assert_eq!(g[0], [(1, "a".to_owned()), (2, "b".to_owned())]);
+}
+
+#[test]
+fn csr() {
+    let g = reader("1 2\n2 3\n4 2\n").read_csr_graph(4, 3, GraphLayout::undirected());
+    assert_eq!(g.len(), 4);
+    assert!(!g.is_empty());
+    assert_eq!(g.edge_count(), 6);
+    assert_eq!(g[1], [0, 2, 3]);
+    assert_eq!(
+        g.iter().map(<[usize]>::to_vec).collect::<Vec<_>>(),
+        [vec![1], vec![0, 2, 3], vec![1], vec![1]]
+    );
+
+    let g: Csr<(usize, u32)> =
+        reader("1 2 5\n1 3 7\n3 1 9\n").read_weighted_csr_graph(3, 3, GraphLayout::directed());
+    assert_eq!(g.neighbors(0), [(1, 5), (2, 7)]);
+    assert_eq!(g.neighbors(1), []);
+    assert_eq!(g.neighbors(2), [(0, 9)]);
+}
+
+#[test]
+fn csr_empty() {
+    let g: Csr<usize> = Csr::from_edges(0, []);
+    assert!(g.is_empty());
+    assert_eq!(Csr::<usize>::default().len(), 0);
+}
+
+#[test]
+#[should_panic = "source 3 is out of range 0..3"]
+fn csr_out_of_range() {
+    let _ = Csr::from_edges(3, [(3, 0)]);
+}
+
+#[test]
+fn tree_parents() {
+    let mut reader = reader("5\n1 1 2 2\n");
+    let n: usize = reader.read();
+    let g = reader.read_tree_parents(n, GraphLayout::directed());
+    assert_eq!(g, [vec![1, 2], vec![3, 4], vec![], vec![], vec![]]);
+
+    let g = self::reader("0 0 1\n").read_tree_parents(4, GraphLayout::undirected().zero_based());
+    assert_eq!(g, [vec![1, 2], vec![0, 3], vec![0], vec![1]]);
+    assert_eq!(
+        self::reader("").read_tree_parents(1, GraphLayout::undirected()),
+        [vec![]]
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let err = reader("1 2\n2 4\n")
+        .try_read_graph(3, 2, GraphLayout::undirected())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::VertexOutOfRange { line: 2, ref expected, found: 4 } if *expected == (1..4)
+    ));
+    assert_eq!(
+        err.to_string(),
+        "expected a vertex in 1..4 on line 2, found 4"
+    );
+
+    let err = reader("0 1\n")
+        .try_read_graph(3, 1, GraphLayout::undirected())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected a vertex in 1..4 on line 1, found 0"
+    );
+
+    let err = reader("1\n5\n")
+        .try_read_tree_parents(3, GraphLayout::undirected())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected a vertex in 1..4 on line 2, found 5"
+    );
+}
+
+#[test]
+#[should_panic = "expected a vertex in 0..3 on line 1, found 3"]
+fn out_of_range_panic() {
+    let _ =
+        reader("3 3 1\n").read_weighted_graph::<u32>(3, 1, GraphLayout::directed().zero_based());
+}
+
+#[test]
+fn too_few_edges() {
+    let err = reader("1 2\n")
+        .try_read_graph(3, 2, GraphLayout::undirected())
+        .unwrap_err();
+    assert!(matches!(err, ReadError::EOF));
+}
@@ -0,0 +1,114 @@
+use iof::{show, ASCIIChar, Grid, InputStream, Mat, ReadError, ReadInto, WriteInto};
+use std::io::Cursor;
+
+#[test]
+fn grid_u8() {
+    let reader = Cursor::new("#.#..\n..#.#\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let grid: Mat<u8> = reader.read_grid(2);
+    assert_eq!(grid, [*b"#.#..", *b"..#.#"]);
+    assert_eq!(Grid(&grid).try_write_into_string().unwrap(), "#.#..\n..#.#");
+}
+
+#[test]
+fn grid_ascii_char() {
+    let reader = Cursor::new("3\nab\ncd\nef\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let n: usize = reader.read();
+    let grid: Mat<ASCIIChar> = reader.read_grid(n);
+    assert_eq!((grid.rows(), grid.cols()), (3, 2));
+    assert_eq!(grid[(2, 1)], ASCIIChar::SmallF);
+    assert_eq!(grid.try_write_into_string().unwrap(), "ab\ncd\nef");
+}
+
+#[test]
+fn grid_char() {
+    let reader = Cursor::new("  αβ  \n\n γδ\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let grid: Mat<char> = reader.read_grid(2);
+    assert_eq!(grid, [['α', 'β'], ['γ', 'δ']]);
+    assert_eq!(Grid(&grid).try_write_into_string().unwrap(), "αβ\nγδ");
+}
+
+#[test]
+fn grid_with_spaces() {
+    let reader = Cursor::new("# #\n#.#\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let grid: Mat<u8> = reader.read_grid(2);
+    assert_eq!(grid, [*b"# #", *b"#.#"]);
+}
+
+#[test]
+fn grid_map() {
+    let reader = Cursor::new("#.\n.#\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let grid = reader.read_grid_map(2, |c: u8| c == b'#');
+    assert_eq!(grid, [[true, false], [false, true]]);
+}
+
+#[test]
+fn grid_ragged() {
+    let reader = Cursor::new("#\n.#.\n##\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let grid: Vec<Vec<u8>> = reader.read_grid_ragged(3);
+    assert_eq!(grid, [b"#".to_vec(), b".#.".to_vec(), b"##".to_vec()]);
+    assert_eq!(Grid(&grid).try_write_into_string().unwrap(), "#\n.#.\n##");
+}
+
+#[test]
+fn grid_ragged_error() {
+    let reader = Cursor::new("#.#\n..\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = ReadInto::<u8>::try_read_grid(&mut reader, 2).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::RaggedRow {
+            line: 2,
+            expected: 3,
+            found: 2
+        }
+    ));
+    assert_eq!(err.to_string(), "expected 3 cells on line 2, found 2");
+}
+
+#[test]
+#[should_panic = "expected 2 cells on line 3, found 1"]
+fn grid_map_ragged_error() {
+    let reader = Cursor::new("##\n..\n#\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _ = reader.read_grid_map(3, |c: char| c == '#');
+}
+
+#[test]
+fn grid_non_ascii() {
+    let reader = Cursor::new("aé\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = ReadInto::<ASCIIChar>::try_read_grid(&mut reader, 1).unwrap_err();
+    assert!(matches!(err, ReadError::FromStrError(..)));
+}
+
+#[test]
+#[should_panic = "expect more characters before EOF"]
+fn grid_eof() {
+    let reader = Cursor::new("#.\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Mat<u8> = reader.read_grid(2);
+}
+
+#[test]
+fn show_grid() {
+    let grid = Mat::from([*b"#.", *b".#"]);
+    let mut buf = Vec::new();
+    show!(Grid(&grid) => &mut buf);
+    assert_eq!(buf, b"#.\n.#\n");
+}
@@ -0,0 +1,137 @@
+use iof::{fmt::Default, unwrap, ASCIIString, InputStream, Mat, ReadFrom, ReadInto, ReadOneFrom};
+use std::io::Cursor;
+
+#[test]
+fn slice() {
+    let reader = Cursor::new("1 2 3\n4 5 6".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut buf = [0u32; 3];
+    reader.read_into_slice(&mut buf);
+    assert_eq!(buf, [1, 2, 3]);
+    reader.read_into_slice(&mut buf[1..]);
+    assert_eq!(buf, [1, 4, 5]);
+    let last: u32 = reader.read();
+    assert_eq!(last, 6);
+}
+
+#[test]
+fn extend() {
+    let reader = Cursor::new("1 2 3 4 5".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut buf: Vec<u32> = Vec::with_capacity(8);
+    let ptr = buf.as_ptr();
+    reader.read_extend(&mut buf, 2);
+    assert_eq!(buf, [1, 2]);
+    buf.clear();
+    reader.read_extend(&mut buf, 3);
+    assert_eq!(buf, [3, 4, 5]);
+    assert_eq!(buf.as_ptr(), ptr);
+
+    assert!(reader.try_read_extend(&mut buf, 1).is_err());
+    assert_eq!(buf, [3, 4, 5]);
+}
+
+#[test]
+fn strings_reuse_buffer() {
+    let reader = Cursor::new("hello world hi".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut buf = vec![String::with_capacity(16), String::with_capacity(16)];
+    let ptrs: Vec<_> = buf.iter().map(|s| s.as_ptr()).collect();
+    reader.read_into_slice(&mut buf);
+    assert_eq!(buf, ["hello", "world"]);
+    assert_eq!(buf.iter().map(|s| s.as_ptr()).collect::<Vec<_>>(), ptrs);
+
+    let mut s = ASCIIString::new();
+    let res = ASCIIString::try_read_in_place_from(&mut reader, &mut s, Default::new());
+    assert!(res.is_ok());
+    assert_eq!(s, *"hi");
+}
+
+#[test]
+fn parse_into() {
+    let mut s = String::from("previous");
+    let ptr = s.as_ptr();
+    assert!(s.parse_into("next").is_ok());
+    assert_eq!(s, "next");
+    assert_eq!(s.as_ptr(), ptr);
+
+    let mut s: ASCIIString = unwrap!(ASCIIString::parse("abc"));
+    assert!(s.parse_into("é").is_err());
+    assert!(s.parse_into("xyz").is_ok());
+    assert_eq!(s, *"xyz");
+
+    let mut x = 0i32;
+    assert!(x.parse_into("-42").is_ok());
+    assert_eq!(x, -42);
+}
+
+#[test]
+fn mat() {
+    let reader = Cursor::new("1 2 3\n4 5 6\n7 8\n9 10\n11 12 13 14".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut mat: Mat<u32> = Mat::new();
+    reader.read_into_mat(&mut mat, 2, 3);
+    assert_eq!(mat, [[1, 2, 3], [4, 5, 6]]);
+    let ptr = mat[0].as_ptr();
+
+    reader.read_into_mat(&mut mat, 2, 2);
+    assert_eq!(mat, [[7, 8], [9, 10]]);
+    assert_eq!(mat[0].as_ptr(), ptr);
+
+    reader.read_into_mat(&mut mat, 1, 4);
+    assert_eq!(mat, [[11, 12, 13, 14]]);
+}
+
+#[test]
+fn tuple_and_array() {
+    let reader = Cursor::new("a 1 b 2\n1 2 3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut pairs = vec![(String::new(), 0u8); 2];
+    reader.read_into_slice(&mut pairs);
+    assert_eq!(pairs, [("a".to_owned(), 1), ("b".to_owned(), 2)]);
+
+    let mut arr = [0i8; 3];
+    let res = <[i8; 3]>::try_read_in_place_from(&mut reader, &mut arr, Default::new());
+    assert!(res.is_ok());
+    assert_eq!(arr, [1, 2, 3]);
+}
+
+/// A digit read as a single character, which overrides only [ReadOneFrom::try_read_one_from].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Digit(u8);
+
+impl ReadOneFrom for Digit {
+    type ParseError = std::num::ParseIntError;
+
+    fn parse(s: &str) -> Result<Self, iof::ReadOneFromError<Self>> {
+        s.parse().map(Self).map_err(|err| {
+            iof::ReadError::FromStrError(err, s.to_owned(), std::any::type_name::<Self>())
+        })
+    }
+
+    fn try_read_one_from<F: iof::fmt::Format, S: iof::BufReadExt>(
+        stream: &mut S,
+        format: F,
+    ) -> Result<Self, iof::ReadOneFromError<Self>> {
+        Self::try_read_in_char_from(stream, format)
+    }
+}
+
+#[test]
+fn custom_try_read_one_from() {
+    let reader = Cursor::new("123\n45".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut buf = [Digit::default(); 3];
+    reader.read_into_slice(&mut buf);
+    assert_eq!(buf, [Digit(1), Digit(2), Digit(3)]);
+
+    let mut mat: Mat<Digit> = Mat::default();
+    reader.read_into_mat(&mut mat, 1, 2);
+    assert_eq!(mat, [[Digit(4), Digit(5)]]);
+}
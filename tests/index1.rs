@@ -0,0 +1,178 @@
+use iof::*;
+use std::io::Cursor;
+
+#[test]
+fn read_one() {
+    let reader = Cursor::new("1 5 -3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Usize1 = reader.read();
+    assert_eq!(a, Index1(0));
+    let b: Index1<u32> = reader.read();
+    assert_eq!(b, Index1(4));
+    let c: Isize1 = reader.read();
+    assert_eq!(c, Index1(-4));
+}
+
+#[test]
+fn read_tuple_and_array() {
+    let reader = Cursor::new("3 1\n2 2 4\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (u, v): (Usize1, Usize1) = reader.read();
+    assert_eq!((u.0, v.0), (2, 0));
+    let a: [Usize1; 3] = reader.read();
+    assert_eq!(a.map(|i| i.0), [1, 1, 3]);
+}
+
+#[test]
+fn read_n() {
+    let reader = Cursor::new("1 2 3 4".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<Usize1> = read!(4; src = reader);
+    assert_eq!(a, [Index1(0), Index1(1), Index1(2), Index1(3)]);
+}
+
+#[test]
+fn read_edges() {
+    let reader = Cursor::new("4 3\n1 2\n2 3\n4 1\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (n, m): (usize, usize) = reader.read();
+    let edges: Vec<(Usize1, Usize1)> = read!(m; src = reader);
+    let mut adj = vec![Vec::new(); n];
+    for (Index1(u), Index1(v)) in edges {
+        adj[u].push(v);
+        adj[v].push(u);
+    }
+    assert_eq!(adj, [vec![1, 3], vec![0, 2], vec![1], vec![0]]);
+}
+
+#[test]
+fn write() {
+    let a = [Index1(0), Index1(1), Index1(usize::MAX)];
+    assert_eq!(
+        unwrap!(a.try_write_into_string()),
+        format!("1 2 {}", usize::MAX as u128 + 1),
+    );
+
+    let a = (Index1(-1), Index1(i8::MAX));
+    assert_eq!(unwrap!(a.try_write_into_string()), "0 128");
+}
+
+#[test]
+fn round_trip() {
+    let a = vec![Index1(3), Index1(0), Index1(7)];
+    let mut buf = Vec::new();
+    show!(a => buf);
+    assert_eq!(String::from_utf8(buf.clone()).unwrap(), "4 1 8\n");
+    let mut reader = InputStream::new(Cursor::new(buf));
+    let b: Vec<Usize1> = reader.read_all();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn read_zero() {
+    let reader = Cursor::new("0".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = Usize1::try_read_one_from(&mut reader, fmt::Default::new()).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::FromStrError(ParseIndex1Error::Zero, _, _)
+    ));
+}
+
+#[test]
+fn read_signed_zero() {
+    let reader = Cursor::new("0 -0 -1".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    for _ in 0..2 {
+        let err = Isize1::try_read_one_from(&mut reader, fmt::Default::new()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadError::FromStrError(ParseIndex1Error::Zero, _, _)
+        ));
+    }
+    let a: Isize1 = reader.read();
+    assert_eq!(a, Index1(-2));
+}
+
+#[test]
+fn read_min() {
+    let reader = Cursor::new("-128 -127".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = <Index1<i8>>::try_read_one_from(&mut reader, fmt::Default::new()).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::FromStrError(ParseIndex1Error::Underflow, _, _)
+    ));
+    let a: Index1<i8> = reader.read();
+    assert_eq!(a, Index1(-128));
+}
+
+#[test]
+#[should_panic = "error during converting a string \"0\" to a value of `iof::index1::Index1<usize>`: 1-based index cannot be 0"]
+fn read_zero_panic() {
+    let reader = Cursor::new("0".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Usize1 = reader.read();
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"x\" during converting it to a value of \"iof::index1::Index1<usize>\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"x\" to a value of `iof::index1::Index1<usize>`: invalid digit found in string"
+)]
+fn read_invalid() {
+    let reader = Cursor::new("x".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Usize1 = reader.read();
+}
+
+#[test]
+fn wide_128() {
+    let reader = Cursor::new(format!("{} 1 {} -5", u128::MAX, i128::MAX).into_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (a, b): (Index1<u128>, Index1<u128>) = reader.read();
+    assert_eq!((a, b), (Index1(u128::MAX - 1), Index1(0)));
+    let (c, d): (Index1<i128>, Index1<i128>) = reader.read();
+    assert_eq!((c, d), (Index1(i128::MAX - 1), Index1(-6)));
+
+    let a = (Index1(u128::MAX), Index1(i128::MAX), Index1(-6i128));
+    assert_eq!(
+        unwrap!(a.try_write_into_string()),
+        "340282366920938463463374607431768211456 170141183460469231731687303715884105728 -5",
+    );
+    assert_eq!(
+        format!("{:>40}", Index1(u128::MAX)),
+        " 340282366920938463463374607431768211456",
+    );
+}
+
+#[test]
+fn read_min_128() {
+    let reader = Cursor::new(format!("{} 0", i128::MIN).into_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = <Index1<i128>>::try_read_one_from(&mut reader, fmt::Default::new()).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::FromStrError(ParseIndex1Error::Underflow, _, _)
+    ));
+    let err = <Index1<u128>>::try_read_one_from(&mut reader, fmt::Default::new()).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::FromStrError(ParseIndex1Error::Zero, _, _)
+    ));
+}
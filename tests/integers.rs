@@ -299,3 +299,63 @@ fn try_write_one_into() {
     let s = String::from_utf8(s).unwrap();
     assert_eq!(s, "42");
 }
+
+#[test]
+fn read_bounds() {
+    let reader = Cursor::new(
+        "255 -128 127 65535 -2147483648 18446744073709551615 -170141183460469231731687303715884105728"
+            .as_bytes(),
+    );
+    let mut reader = InputStream::new(reader);
+
+    let a: (u8, i8, i8, u16, i32, u64, i128) = reader.read();
+    assert_eq!(
+        a,
+        (
+            u8::MAX,
+            i8::MIN,
+            i8::MAX,
+            u16::MAX,
+            i32::MIN,
+            u64::MAX,
+            i128::MIN
+        )
+    );
+}
+
+#[test]
+#[should_panic = "error during converting a string \"256\" to a value of `u8`: number too large to fit in target type"]
+fn read_overflow() {
+    let reader = Cursor::new("256".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: u8 = reader.read();
+}
+
+#[test]
+#[should_panic = "error during converting a string \"-129\" to a value of `i8`: number too small to fit in target type"]
+fn read_underflow() {
+    let reader = Cursor::new("-129".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: i8 = reader.read();
+}
+
+#[test]
+fn read_non_zero() {
+    use std::num::{NonZeroI32, NonZeroU32};
+
+    let reader = Cursor::new("1 -2 0".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: NonZeroU32 = reader.read();
+    assert_eq!(a.get(), 1);
+    let b: NonZeroI32 = reader.read();
+    assert_eq!(b.get(), -2);
+    let c: Result<NonZeroU32, _> = reader.try_read();
+    assert!(c
+        .unwrap_err()
+        .to_string()
+        .ends_with("number would be zero for non-zero type"));
+    assert!(<NonZeroU32>::try_read_from(&mut reader, Default::new()).is_err());
+}
@@ -0,0 +1,102 @@
+use iof::{fmt::Default, read, unwrap, InputStream, LenPrefixed, ReadFrom, ReadInto};
+use std::{collections::BTreeSet, io::Cursor};
+
+#[test]
+fn vec() {
+    let reader = Cursor::new("3 1 2 3\n0\n2\n4\n5".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: LenPrefixed<Vec<u32>> = reader.read();
+    assert_eq!(a.0, [1, 2, 3]);
+    let b: LenPrefixed<Vec<u32>> = reader.read();
+    assert!(b.is_empty());
+    let c: LenPrefixed<Vec<u32>> = reader.read();
+    assert_eq!(c.into_inner(), [4, 5]);
+
+    assert!(<LenPrefixed<Vec<u32>>>::try_read_from(&mut reader, Default::new()).is_err());
+}
+
+#[test]
+fn string() {
+    let reader = Cursor::new("5 hello 3 a b c".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: LenPrefixed<String> = reader.read();
+    assert_eq!(*a, "hello");
+    let b: LenPrefixed<String> = reader.read();
+    assert_eq!(*b, "abc");
+}
+
+#[test]
+fn set() {
+    let reader = Cursor::new("4 3 1 3 2".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: LenPrefixed<BTreeSet<u32>> = reader.read();
+    assert_eq!(a.0, BTreeSet::from([1, 2, 3]));
+}
+
+#[test]
+fn tuple() {
+    let reader = Cursor::new("alice 2 90 80\nbob 1 70".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let students: Vec<(String, LenPrefixed<Vec<u32>>)> = reader.read_n(2);
+    assert_eq!(students[0].0, "alice");
+    assert_eq!(*students[0].1, [90, 80]);
+    assert_eq!(students[1].0, "bob");
+    assert_eq!(*students[1].1, [70]);
+}
+
+#[test]
+fn jagged() {
+    let reader = Cursor::new("3\n2 1 2\n0\n3 3 4 5\n2 6 7".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let m: usize = read!(; src = reader);
+    let mat: Vec<Vec<u32>> = read!(m, ..; src = reader);
+    assert_eq!(mat, [vec![1, 2], vec![], vec![3, 4, 5]]);
+    let row: Vec<u32> = read!(..; src = reader);
+    assert_eq!(row, [6, 7]);
+}
+
+#[test]
+fn jagged_skip() {
+    let reader = Cursor::new("2,a,b;3,c,d,e".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let rows: Vec<String> = read!(2, ..; src = reader; skip = [',', ';']);
+    assert_eq!(rows, ["ab", "cde"]);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"-\" during converting it to a value of \"usize\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"-1\" to a value of `usize`: invalid digit found in string"
+)]
+fn invalid_len() {
+    let reader = Cursor::new("-1 2".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Vec<u32> = read!(..; src = reader);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"x\" during converting it to a value of \"u32\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"x\" to a value of `u32`: invalid digit found in string"
+)]
+fn invalid_item() {
+    let reader = Cursor::new("2 1 x".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: LenPrefixed<Vec<u32>> = unwrap!(reader.try_read());
+}
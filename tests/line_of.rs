@@ -0,0 +1,111 @@
+use iof::{BufReadExt, InputStream, Mat, ReadError, ReadInto, ReadOneInto};
+use std::io::Cursor;
+
+#[test]
+fn line_of() {
+    let reader = Cursor::new("3\n1 2 3\n4 5 6\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let n: usize = reader.read();
+    let a: Vec<u32> = reader.read_line_of(n);
+    assert_eq!(a, [1, 2, 3]);
+    let b: Vec<u32> = reader.read_line_of(n);
+    assert_eq!(b, [4, 5, 6]);
+}
+
+#[test]
+fn line_of_rest() {
+    let reader = Cursor::new("3 1 2 3\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let n: usize = reader.read();
+    let a: Vec<u32> = reader.read_line_of(n);
+    assert_eq!(a, [1, 2, 3]);
+}
+
+#[test]
+fn line_of_zero() {
+    let reader = Cursor::new("1\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<u32> = reader.read_line_of(0);
+    assert!(a.is_empty());
+    let b: u32 = reader.read();
+    assert_eq!(b, 1);
+}
+
+#[test]
+fn too_few() {
+    let reader = Cursor::new("1 2 3 4\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = ReadOneInto::<u32>::try_read_line_of(&mut reader, 5).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::TokenCount {
+            line: 1,
+            expected: 5,
+            found: 4
+        }
+    ));
+    assert_eq!(err.to_string(), "expected 5 tokens on line 1, found 4");
+}
+
+#[test]
+fn too_many() {
+    let reader = Cursor::new("1 2 3\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = ReadOneInto::<u32>::try_read_line_of(&mut reader, 2).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::TokenCount {
+            line: 1,
+            expected: 2,
+            found: 3
+        }
+    ));
+}
+
+#[test]
+fn rows() {
+    let reader = Cursor::new("2 3\n1 2 3\n\n4 5 6\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let (m, n): (usize, usize) = reader.read();
+    let mat: Mat<u32> = reader.read_rows(m, n);
+    assert_eq!(mat, [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(reader.line_number(), 4);
+}
+
+#[test]
+#[should_panic = "expected 5 tokens on line 3, found 4"]
+fn rows_mismatch() {
+    let reader = Cursor::new("1 2 3 4 5\n6 7 8 9 10\n11 12 13 14\n15 16 17 18 19 20\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Mat<u32> = reader.read_rows(4, 5);
+}
+
+#[test]
+#[should_panic = "expected 3 tokens on line 1, found 6"]
+fn rows_in_one_line() {
+    let reader = Cursor::new("1 2 3 4 5 6\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Mat<u32> = reader.read_rows(2, 3);
+}
+
+#[test]
+fn line_number() {
+    let reader = Cursor::new("1\n2\n\n3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    assert_eq!(reader.line_number(), 0);
+    let _: u32 = reader.read();
+    assert_eq!(reader.line_number(), 1);
+    let _: u32 = reader.read();
+    assert_eq!(reader.line_number(), 2);
+    let _: u32 = reader.read();
+    assert_eq!(reader.line_number(), 4);
+}
@@ -0,0 +1,60 @@
+use iof::{unwrap, Sorted, WriteInto};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[test]
+fn btree_map() {
+    let map = BTreeMap::from([(3, "c"), (1, "a"), (2, "b")]);
+    assert_eq!(unwrap!(map.try_write_into_string()), "1 a\n2 b\n3 c");
+    assert_eq!(
+        unwrap!(map.try_write_into_string_with_sep(&[", ", ": "])),
+        "1: a, 2: b, 3: c",
+    );
+    assert_eq!(
+        unwrap!(map.try_write_into_string_with_sep(&[" "])),
+        "1 a 2 b 3 c",
+    );
+}
+
+#[test]
+fn empty() {
+    let map = BTreeMap::<u32, u32>::new();
+    assert_eq!(unwrap!(map.try_write_into_string()), "");
+    let map = HashMap::<u32, u32>::new();
+    assert_eq!(unwrap!(Sorted(&map).try_write_into_string()), "");
+}
+
+#[test]
+fn hash_map_single() {
+    let map = HashMap::from([('x', 1.5)]);
+    assert_eq!(unwrap!(map.try_write_into_string()), "x 1.5");
+}
+
+#[test]
+fn hash_map_sorted() {
+    let map: HashMap<_, _> = (0..100).rev().map(|i| (i, i * i)).collect();
+    let expected: Vec<_> = (0..100).map(|i| format!("{i} {}", i * i)).collect();
+    assert_eq!(
+        unwrap!(Sorted(&map).try_write_into_string()),
+        expected.join("\n"),
+    );
+    assert_eq!(
+        unwrap!(Sorted(&map).try_write_into_string_with_sep(&["; ", "->"])),
+        (0..100)
+            .map(|i| format!("{i}->{}", i * i))
+            .collect::<Vec<_>>()
+            .join("; "),
+    );
+
+    let mut lines: Vec<_> = unwrap!(map.try_write_into_string())
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    lines.sort_by_key(|line| unwrap!(line.split(' ').next().unwrap_or("").parse::<u32>()));
+    assert_eq!(lines, expected);
+}
+
+#[test]
+fn hash_set_sorted() {
+    let set = HashSet::from([5, 3, 4, 1, 2]);
+    assert_eq!(unwrap!(Sorted(&set).try_write_into_string()), "1 2 3 4 5");
+}
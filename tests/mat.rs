@@ -106,7 +106,7 @@ fn macro_read_mat() {
     let reader = Cursor::new("123\n456".as_bytes());
     let mut reader = InputStream::new(reader);
 
-    let mat: Mat<char> = read!(2, 3; src = &mut reader; fmt = Default::new());
+    let mat: Mat<char> = read!(mat 2, 3; src = &mut reader; fmt = Default::new());
     assert_eq!(mat, [['1', '2', '3'], ['4', '5', '6']]);
 
     let reader = Cursor::new("123\n456".as_bytes());
@@ -116,7 +116,7 @@ fn macro_read_mat() {
 
     let reader = Cursor::new("1,2,3\n4,5,6".as_bytes());
     let mut reader = InputStream::new(reader);
-    let mat: Mat<char> = read!(2, 3; src = &mut reader; fmt = csv());
+    let mat: Mat<char> = read!(mat 2, 3; src = &mut reader; fmt = csv());
     assert_eq!(mat, [['1', '2', '3'], ['4', '5', '6']]);
 }
 
@@ -175,3 +175,58 @@ fn complex() {
         Mat::from(vec![vec![0, 0, 1], vec![0, 1, 0], vec![1, 0, 0]]),
     );
 }
+
+#[test]
+fn shape() {
+    let reader = Cursor::new("1 2 3\n4 5 6".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut mat: Mat<u32> = reader.read_m_n(2, 3);
+    assert_eq!((mat.rows(), mat.cols()), (2, 3));
+    assert_eq!(mat.as_slice(), [1, 2, 3, 4, 5, 6]);
+    assert_eq!(mat[(1, 0)], 4);
+    mat[(1, 0)] = 7;
+    mat[0][2] = 9;
+    assert_eq!(mat, [[1, 2, 9], [7, 5, 6]]);
+    assert_eq!(mat.col(2).copied().collect::<Vec<_>>(), [9, 6]);
+
+    let transposed = mat.transpose();
+    assert_eq!(transposed, [[1, 7], [2, 5], [9, 6]]);
+    assert_eq!(unwrap!(transposed.try_write_into_string()), "1 7\n2 5\n9 6");
+    assert_eq!(
+        unwrap!(transposed.try_write_into_string_with_sep(&["; ", ","])),
+        "1,7; 2,5; 9,6",
+    );
+
+    let rows: Vec<Vec<u32>> = mat.into();
+    assert_eq!(rows, [[1, 2, 9], [7, 5, 6]]);
+}
+
+#[test]
+fn empty_rows_col() {
+    let reader = Cursor::new("".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mat: Mat<u32> = reader.read_m_n(0, 3);
+    assert_eq!((mat.rows(), mat.cols()), (0, 3));
+    for j in 0..3 {
+        assert_eq!(mat.col(j).count(), 0);
+    }
+
+    let mat = Mat::<u32>::from_vec(0, 3, vec![]);
+    assert_eq!(mat.col(2).len(), 0);
+}
+
+#[test]
+#[should_panic = "row 1 has 1 elements, but previous rows have 2"]
+fn ragged() {
+    let reader = Cursor::new("1 2\n3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut i = 2;
+    let rows: Vec<Vec<u32>> = read!(2, {
+        i -= 1;
+        i + 1
+    }; src = reader);
+    let _: Mat<u32> = rows.into_iter().collect();
+}
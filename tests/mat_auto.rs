@@ -0,0 +1,78 @@
+use iof::{fmt, InputStream, Mat, ReadError, ReadInto, ReadOneFrom, ReadOneInto};
+use std::io::Cursor;
+
+#[test]
+fn until_eof() {
+    let reader = Cursor::new("1 2 3\n4 5 6\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mat: Mat<u32> = reader.read_mat_auto();
+    assert_eq!(mat, [[1, 2, 3], [4, 5, 6]]);
+}
+
+#[test]
+fn until_eof_without_newline() {
+    let reader = Cursor::new("1 2\n3 4".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mat: Mat<u32> = reader.read_mat_auto();
+    assert_eq!(mat, [[1, 2], [3, 4]]);
+}
+
+#[test]
+fn until_blank_line() {
+    let reader = Cursor::new("2\n1 2\n3 4\n  \n5 6 7\n8 9 10\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let k: u32 = reader.read();
+    assert_eq!(k, 2);
+    let a: Mat<u32> = reader.read_mat_auto();
+    assert_eq!(a, [[1, 2], [3, 4]]);
+    let b: Mat<u32> = reader.read_mat_auto();
+    assert_eq!(b, [[5, 6, 7], [8, 9, 10]]);
+    let c: Mat<u32> = reader.read_mat_auto();
+    assert!(c.is_empty());
+}
+
+#[test]
+fn csv() {
+    let reader = Cursor::new("\n1,2,3\n4, 5, 6\n\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mat = i32::try_read_mat_auto_from(&mut reader, fmt::csv()).unwrap();
+    assert_eq!(mat, [[1, 2, 3], [4, 5, 6]]);
+}
+
+#[test]
+fn ragged() {
+    let reader = Cursor::new("1 2 3\n4 5 6\n7 8\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let err = ReadOneInto::<u32>::try_read_mat_auto(&mut reader).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::RaggedRow {
+            line: 3,
+            expected: 3,
+            found: 2
+        }
+    ));
+}
+
+#[test]
+#[should_panic = "expected 1 cells on line 2, found 3"]
+fn ragged_panic() {
+    let reader = Cursor::new("1\n2 3 4\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Mat<u32> = reader.read_mat_auto();
+}
+
+#[test]
+fn empty() {
+    let reader = Cursor::new("".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mat: Mat<u32> = reader.read_mat_auto();
+    assert_eq!((mat.rows(), mat.cols()), (0, 0));
+}
@@ -0,0 +1,86 @@
+use iof::{fmt::Default, unwrap, InputStream, ReadError, ReadFrom, ReadInto};
+use std::io::Cursor;
+
+#[test]
+fn read_opt_until_eof() {
+    let reader = Cursor::new("1 2\n3\n\n  \n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut v: Vec<u32> = Vec::new();
+    while let Some(x) = reader.read_opt() {
+        v.push(x);
+    }
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(unwrap!(reader.try_read_opt()), None::<u32>);
+}
+
+#[test]
+fn read_opt_empty() {
+    let reader = Cursor::new("".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    assert_eq!(reader.read_opt(), None::<(u32, u32)>);
+}
+
+#[test]
+fn read_opt_tuple() {
+    let reader = Cursor::new("1 a\n2 b\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut v: Vec<(u32, char)> = Vec::new();
+    while let Some(pair) = reader.read_opt() {
+        v.push(pair);
+    }
+    assert_eq!(v, [(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn read_opt_cut_in_half() {
+    let reader = Cursor::new("1 2 3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Option<(u32, u32)> = reader.read_opt();
+    assert_eq!(a, Some((1, 2)));
+    let b: Result<Option<(u32, u32)>, _> = reader.try_read_opt();
+    assert!(matches!(b, Err(ReadError::EOF)));
+
+    let reader = Cursor::new("1 2".as_bytes());
+    let mut reader = InputStream::new(reader);
+    let c: Result<Option<[u32; 3]>, _> = reader.try_read_opt();
+    assert!(matches!(c, Err(ReadError::EOF)));
+}
+
+#[test]
+fn option_in_line() {
+    let reader = Cursor::new("1 2\n3\n4 5 \n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let rows: Vec<(u32, Option<u32>)> = reader.read_n(3);
+    assert_eq!(rows, [(1, Some(2)), (3, None), (4, Some(5))]);
+    let last: Option<u32> = reader.read();
+    assert_eq!(last, None);
+}
+
+#[test]
+fn option_fresh_stream() {
+    let reader = Cursor::new("5\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Option<u32> = reader.read();
+    assert_eq!(a, Some(5));
+    let b: Option<u32> = reader.read();
+    assert_eq!(b, None);
+}
+
+#[test]
+fn option_consumed_line() {
+    let reader = Cursor::new("1".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a = <Option<u32>>::try_read_from(&mut reader, Default::new());
+    assert_eq!(unwrap!(a), Some(1));
+    let b = <Option<u32>>::try_read_from(&mut reader, Default::new());
+    assert_eq!(unwrap!(b), None);
+    let c = <Option<u32>>::try_read_opt_from(&mut reader, Default::new());
+    assert_eq!(unwrap!(c), None);
+}
@@ -0,0 +1,108 @@
+use iof::{
+    radix::{AutoRadix, Bin, Hex, Oct},
+    *,
+};
+use std::io::Cursor;
+
+#[test]
+fn read_hex() {
+    let reader = Cursor::new("deadbeef 0xCAFEBABE -0x80 7f".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Hex<u32> = reader.read();
+    assert_eq!(a, Hex(0xdeadbeef));
+    let b: Hex<u32> = reader.read();
+    assert_eq!(b, Hex(0xcafebabe));
+    let c: (Hex<i8>, Hex<i8>) = reader.read();
+    assert_eq!(c, (Hex(i8::MIN), Hex(i8::MAX)));
+
+    assert!(<Hex<u8>>::try_read_one_from(&mut reader, fmt::Default::new()).is_err());
+}
+
+#[test]
+fn read_n() {
+    let reader = Cursor::new("0b1011 0o17 0xff 42 -0b1".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<AutoRadix<i64>> = read!(5; src = reader);
+    assert_eq!(
+        a,
+        [
+            AutoRadix(0b1011),
+            AutoRadix(0o17),
+            AutoRadix(0xff),
+            AutoRadix(42),
+            AutoRadix(-1)
+        ]
+    );
+}
+
+#[test]
+fn read_mat() {
+    let reader = Cursor::new("0 1\n1 0\n".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Mat<Bin<u8>> = read!(mat 2, 2; src = reader);
+    assert_eq!(a, [[Bin(0), Bin(1)], [Bin(1), Bin(0)]]);
+    assert_eq!(unwrap!(a.try_write_into_string()), "0 1\n1 0");
+}
+
+#[test]
+fn write() {
+    let a = [Hex(255u8), Hex(0), Hex(16)];
+    assert_eq!(unwrap!(a.try_write_into_string()), "ff 0 10");
+
+    let a = (Oct(-8i32), Bin(5u64), AutoRadix(-3i16));
+    assert_eq!(unwrap!(a.try_write_into_string()), "-10 101 -3");
+}
+
+#[test]
+fn round_trip() {
+    let a = [Hex(i32::MIN), Hex(-1), Hex(0), Hex(i32::MAX)];
+    let s = unwrap!(a.try_write_into_string());
+    let mut reader = InputStream::new(Cursor::new(s.as_bytes()));
+    let b: [Hex<i32>; 4] = reader.read();
+    assert_eq!(a, b);
+}
+
+#[test]
+#[cfg_attr(
+    feature = "c-compatible",
+    should_panic = "found unexpected character at the end of the string \"-\" during converting it to a value of \"iof::radix::Hex<u8>\""
+)]
+#[cfg_attr(
+    not(feature = "c-compatible"),
+    should_panic = "error during converting a string \"-ff\" to a value of `iof::radix::Hex<u8>`: invalid digit found in string"
+)]
+fn read_sign_error() {
+    let reader = Cursor::new("-ff".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Hex<u8> = reader.read();
+}
+
+#[test]
+#[cfg(feature = "c-compatible")]
+fn read_truncated() {
+    let reader = Cursor::new("0x1fg 0b102 0x 017,".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Hex<u32> = reader.read();
+    assert_eq!(a, Hex(0x1f));
+    assert_eq!(reader.get_cur_line(), "g 0b102 0x 017,");
+    let _: char = reader.read();
+
+    let b: Bin<u32> = reader.read();
+    assert_eq!(b, Bin(0b10));
+    assert_eq!(reader.get_cur_line(), "2 0x 017,");
+    let _: char = reader.read();
+
+    let c: Hex<u32> = reader.read();
+    assert_eq!(c, Hex(0));
+    assert_eq!(reader.get_cur_line(), "x 017,");
+    let _: char = reader.read();
+
+    let d: AutoRadix<u32> = reader.read();
+    assert_eq!(d, AutoRadix(17));
+    assert_eq!(reader.get_cur_line(), ",");
+}
@@ -13,7 +13,7 @@ fn read_m_n_0_0() {
     let s1: Mat<usize> = read_m_n(0, 1);
     let s2: Mat<char> = read_m_n(1, 0);
     assert!(s1.is_empty());
-    assert_eq!(s2.len(), 1);
+    assert_eq!(s2.rows(), 1);
     assert!(s2[0].is_empty());
 }
 
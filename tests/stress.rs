@@ -0,0 +1,143 @@
+use iof::{
+    gen::Rng, read, read_all, show, stress, try_stress, try_stress_shrinking, Shrink, StressError,
+    WriteInto,
+};
+use std::io::Write;
+
+fn sum_brute() {
+    let n: usize = read!();
+    let a: Vec<i64> = read!(n);
+    let mut sum = 0;
+    for x in a {
+        sum += x;
+    }
+    show!(sum);
+}
+
+fn sum_fast() {
+    let n: usize = read!();
+    let a: Vec<i64> = read!(n);
+    show!(a.iter().sum::<i64>());
+}
+
+fn sum_wrong() {
+    let n: usize = read!();
+    let a: Vec<i64> = read!(n);
+    show!(a.iter().filter(|&&x| x > 0).sum::<i64>());
+}
+
+fn gen_sum(rng: &mut Rng) -> String {
+    let n: usize = rng.int(1..=10);
+    let a: Vec<i64> = rng.ints(n, -100..=100);
+    format!("{n}\n{}", a.try_write_into_string().unwrap())
+}
+
+#[test]
+fn same() {
+    try_stress(gen_sum, sum_brute, sum_fast, 200).unwrap();
+    stress(gen_sum, sum_brute, sum_fast, 200);
+}
+
+#[test]
+fn differ() {
+    let err = try_stress(gen_sum, sum_brute, sum_wrong, 200).unwrap_err();
+    let StressError::Differ(counterexample) = err else {
+        panic!("unexpected error");
+    };
+    // Reproducible from the seed.
+    let input = gen_sum(&mut Rng::new(counterexample.seed));
+    assert_eq!(counterexample.input, input + "\n");
+    assert_ne!(counterexample.expected, counterexample.found);
+    assert_eq!(
+        (counterexample.mismatch.line, counterexample.mismatch.token),
+        (1, 1)
+    );
+}
+
+#[test]
+#[should_panic = "solutions differ on seed"]
+fn differ_panic() {
+    stress(gen_sum, sum_brute, sum_wrong, 200);
+}
+
+fn max_brute() {
+    let a: Vec<i32> = read_all();
+    show!(a.iter().max().unwrap_or(&0));
+}
+
+fn max_wrong() {
+    let a: Vec<i32> = read_all();
+    show!(a.iter().take(3).max().unwrap_or(&0));
+}
+
+#[test]
+fn shrinking() {
+    let gen = |rng: &mut Rng| rng.ints(20, -50..=50);
+    let err = try_stress_shrinking(gen, max_brute, max_wrong, 100).unwrap_err();
+    let StressError::Differ(counterexample) = err else {
+        panic!("unexpected error");
+    };
+    assert_eq!(counterexample.input, "0 0 0 1\n");
+}
+
+#[test]
+fn shrink_candidates() {
+    assert!(0u32.shrink().is_empty());
+    assert_eq!(1u32.shrink(), [0]);
+    assert_eq!(10u32.shrink(), [0, 5, 9]);
+    assert_eq!((-10i32).shrink(), [0, -5, -9]);
+    assert_eq!(
+        vec![3u8, 0].shrink(),
+        [
+            vec![0],
+            vec![3],
+            vec![0],
+            vec![3],
+            vec![0, 0],
+            vec![1, 0],
+            vec![2, 0]
+        ]
+    );
+}
+
+#[test]
+fn redirect_stdio() {
+    let echo = || {
+        let line = iof::get_line();
+        let mut out = iof::stdout();
+        writeln!(out, "{line}").unwrap();
+    };
+    let gen = |rng: &mut Rng| rng.ints(3, 0..10u32);
+    let show_read = || {
+        let a: Vec<u32> = read!(3);
+        show!(a);
+    };
+    try_stress(gen, echo, show_read, 10).unwrap();
+}
+
+#[test]
+#[should_panic = "redirected standard input is already locked by current thread"]
+fn redirect_stdin_locked() {
+    let lock_twice = || {
+        let _stdin = iof::stdin();
+        drop(iof::stdin());
+    };
+    stress(|rng| rng.int(0..10u32), lock_twice, sum_fast, 1);
+}
+
+#[test]
+fn redirect_restored_after_panic() {
+    let result = std::panic::catch_unwind(|| {
+        stress(
+            |rng| rng.int(0..10u32),
+            || {
+                let _stdin = iof::stdin();
+                panic!("boom");
+            },
+            sum_fast,
+            1,
+        )
+    });
+    assert!(result.is_err());
+    stress(gen_sum, sum_brute, sum_fast, 10);
+}
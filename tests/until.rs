@@ -0,0 +1,120 @@
+use iof::{read, unwrap, InputStream, ReadInto};
+use std::io::Cursor;
+
+#[test]
+fn until_zero() {
+    let reader = Cursor::new("3 1 4 0 1 5".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<u32> = reader.read_until(0);
+    assert_eq!(a, [3, 1, 4]);
+    let b: Vec<u32> = reader.read_n(2);
+    assert_eq!(b, [1, 5]);
+}
+
+#[test]
+fn until_tuple() {
+    let reader = Cursor::new("1 2\n3 4\n0 0\n5".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<(i32, i32)> = reader.read_until((0, 0));
+    assert_eq!(a, [(1, 2), (3, 4)]);
+    let b: i32 = reader.read();
+    assert_eq!(b, 5);
+}
+
+#[test]
+fn until_string() {
+    let reader = Cursor::new("apple banana END cherry".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<String> = reader.read_until("END");
+    assert_eq!(a, ["apple", "banana"]);
+    let b: String = reader.read();
+    assert_eq!(b, "cherry");
+}
+
+#[test]
+fn until_empty() {
+    let reader = Cursor::new("-1".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<i32> = reader.read_until(-1);
+    assert!(a.is_empty());
+}
+
+#[test]
+fn while_positive() {
+    let reader = Cursor::new("3 1 4 -1 5".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<i32> = reader.read_while(|x| *x > 0);
+    assert_eq!(a, [3, 1, 4]);
+    let b: i32 = reader.read();
+    assert_eq!(b, 5);
+}
+
+#[test]
+fn iter() {
+    let reader = Cursor::new("1 2 3 0 4 5 6 0".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut sum = 0;
+    for x in reader.iter_until(0) {
+        let x: u32 = unwrap!(x);
+        sum += x;
+    }
+    assert_eq!(sum, 6);
+
+    let mut iter = reader.iter_while(|x: &u32| *x != 0);
+    assert_eq!(unwrap!(iter.next().transpose()), Some(4));
+    assert_eq!(unwrap!(iter.next().transpose()), Some(5));
+    assert_eq!(unwrap!(iter.next().transpose()), Some(6));
+    assert_eq!(unwrap!(iter.next().transpose()), None);
+    assert_eq!(unwrap!(iter.next().transpose()), None);
+}
+
+#[test]
+fn iter_eof() {
+    let reader = Cursor::new("1 2".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let mut iter = reader.iter_until(0);
+    assert_eq!(unwrap!(iter.next().transpose()), Some(1u32));
+    assert_eq!(unwrap!(iter.next().transpose()), Some(2));
+    assert!(iter.next().is_some_and(|res| res.is_err()));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn macro_until() {
+    let reader = Cursor::new("1 2 0\n3,4,-1\na b END".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<u32> = read!(until 0; src = reader);
+    assert_eq!(a, [1, 2]);
+    let b: Vec<i32> = read!(until -1; src = reader; skip = [' ', ',', '\n']);
+    assert_eq!(b, [3, 4]);
+    let c: Vec<String> = read!(until "END"; src = reader);
+    assert_eq!(c, ["a", "b"]);
+}
+
+#[test]
+fn macro_while() {
+    let reader = Cursor::new("2 4 6 7 8".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a: Vec<u32> = read!(while |x: &u32| x % 2 == 0; src = reader);
+    assert_eq!(a, [2, 4, 6]);
+    let b: u32 = read!(; src = reader);
+    assert_eq!(b, 8);
+}
+
+#[test]
+#[should_panic = "expect more characters before EOF"]
+fn missing_sentinel() {
+    let reader = Cursor::new("1 2 3".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let _: Vec<u32> = reader.read_until(0);
+}
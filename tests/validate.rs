@@ -0,0 +1,177 @@
+use iof::{
+    ext::{Pattern, State},
+    validate::{Position, StrictReal, StrictStream, ValidateError},
+    InputStream,
+};
+use std::io::Cursor;
+
+fn strict(s: &str) -> StrictStream<InputStream<Cursor<&[u8]>>> {
+    StrictStream::new(InputStream::new(Cursor::new(s.as_bytes())))
+}
+
+#[test]
+fn valid() {
+    let mut stream = strict("2 -5\n0.50 abc\n");
+    let n: u32 = stream.read_int_in(1..=2, "n");
+    stream.read_space();
+    let m: i64 = stream.read_int_in(-10..=10, "m");
+    stream.read_eoln();
+    let x = stream.read_real_in(0.0..=1.0, 1..=2, "x");
+    stream.read_space();
+    let s = stream.read_token_matching(Lower, "s");
+    stream.read_eoln();
+    stream.read_eof();
+    assert_eq!((n, m, x, s.as_str()), (2, -5, 0.5, "abc"));
+}
+
+#[test]
+fn double_space() {
+    let mut stream = strict("1  2\n");
+    let _: u32 = stream.read_int_in(0..=9, "a");
+    stream.read_space();
+    let err = stream.try_read_int_in(0..=9u32, "b").unwrap_err();
+    assert!(matches!(
+        err,
+        ValidateError::Unexpected {
+            position: Position { line: 1, column: 3 },
+            expected: "a token",
+            found: Some(' '),
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 3: expected a token, found ' '"
+    );
+}
+
+#[test]
+fn trailing_space() {
+    let mut stream = strict("1 \n");
+    let _: u32 = stream.read_int_in(0..=9, "a");
+    let err = stream.try_read_eoln().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 2: expected end of line, found ' '"
+    );
+}
+
+#[test]
+fn missing_final_newline() {
+    let mut stream = strict("1\n2");
+    let _: u32 = stream.read_int_in(0..=9, "a");
+    stream.read_eoln();
+    let _: u32 = stream.read_int_in(0..=9, "b");
+    let err = stream.try_read_eoln().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 2: expected end of line, found end of file"
+    );
+}
+
+#[test]
+fn extra_line() {
+    let mut stream = strict("1\n\n");
+    let _: u32 = stream.read_int_in(0..=9, "a");
+    stream.read_eoln();
+    let err = stream.try_read_eof().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 1: expected end of file, found '\\n'"
+    );
+}
+
+#[test]
+fn crlf() {
+    let mut stream = strict("1\r\n");
+    let _: u32 = stream.read_int_in(0..=9, "a");
+    assert!(stream.try_read_eoln().is_err());
+}
+
+#[test]
+fn leading_zero() {
+    for s in ["01\n", "-01\n", "00\n", "-0\n", "+1\n", "1a\n", "-\n"] {
+        let mut stream = strict(s);
+        let err = stream.try_read_int_in(-9..=9i32, "a").unwrap_err();
+        assert!(
+            matches!(err, ValidateError::InvalidToken { .. }),
+            "{s:?}: {err}"
+        );
+    }
+    let mut stream = strict("0\n");
+    assert_eq!(stream.read_int_in(-9..=9i32, "a"), 0);
+}
+
+#[test]
+fn out_of_range() {
+    let mut stream = strict("1 300\n");
+    let _: u8 = stream.read_int_in(0..=9, "a");
+    stream.read_space();
+    let err = stream.try_read_int_in(0..=9u8, "b").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 3: b = 300 is out of range 0..=9"
+    );
+}
+
+#[test]
+#[should_panic = "line 1, column 1: a = 10 is out of range 1..=9"]
+fn out_of_range_panic() {
+    let mut stream = strict("10\n");
+    let _: u32 = stream.read_int_in(1..=9, "a");
+}
+
+#[test]
+fn real() {
+    let mut stream = strict("-0.5 1 2.000\n");
+    assert_eq!(stream.read_real_in(-1.0..=1.0, 0..=3, "a"), -0.5);
+    stream.read_space();
+    assert_eq!(stream.read_real_in(-1.0..=1.0, 0..=3, "b"), 1.0);
+    stream.read_space();
+    assert_eq!(stream.read_real_in(0.0..=3.0, 0..=3, "c"), 2.0);
+    stream.read_eoln();
+    stream.read_eof();
+}
+
+#[test]
+fn real_invalid() {
+    for s in [
+        "1.\n", ".5\n", "-0.0\n", "-0\n", "01.5\n", "+1.5\n", "1.2345\n", "1e5\n",
+    ] {
+        let mut stream = strict(s);
+        let err = stream.try_read_real_in(-9.0..=9.0, 0..=3, "x").unwrap_err();
+        assert!(
+            matches!(err, ValidateError::InvalidToken { .. }),
+            "{s:?}: {err}"
+        );
+    }
+    let mut stream = strict("1\n");
+    let err = stream.try_read_real_in(-9.0..=9.0, 1..=3, "x").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: invalid x \"1\"");
+}
+
+#[test]
+fn real_pattern() {
+    assert_eq!(StrictReal::default().forward::<()>("-0.25 1"), Ok(5));
+}
+
+#[derive(Clone, Copy)]
+struct Lower;
+
+impl Pattern for Lower {
+    type Item = char;
+
+    fn step(&mut self, c: char) -> bool {
+        c.is_ascii_lowercase()
+    }
+
+    fn state(&self) -> State {
+        State::Stoppable
+    }
+}
+
+#[test]
+fn token_matching() {
+    let mut stream = strict("ab1\n");
+    let err = stream.try_read_token_matching(Lower, "s").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 1: invalid s \"ab1\"");
+}
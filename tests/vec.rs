@@ -14,6 +14,31 @@ fn read_n() {
     assert!(<u32>::try_read_n_from(&mut reader, 1, Default::new()).is_err());
 }
 
+#[test]
+fn read_n_macro_infers_vec() {
+    let reader = Cursor::new("1 2 3\n4 5 6".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a = read!(3; src = reader);
+    let x: u32 = a[0];
+    assert_eq!(x, 1);
+    let b = read!(3; src = reader);
+    let y: u32 = b[1];
+    assert_eq!(y, 5);
+}
+
+#[test]
+fn read_m_n_macro_infers_vec() {
+    let reader = Cursor::new("1 2\n3 4".as_bytes());
+    let mut reader = InputStream::new(reader);
+
+    let a = read!(2, 2; src = reader);
+    assert_eq!(a.len(), 2);
+    let row: &Vec<u32> = &a[0];
+    assert_eq!(row, &[1, 2]);
+    assert_eq!(a[1], [3, 4]);
+}
+
 #[test]
 fn read() {
     let reader = Cursor::new("1 2 3\n 4 5 6\n 7   8".as_bytes());